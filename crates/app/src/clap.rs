@@ -13,6 +13,11 @@ struct Cli {
     #[arg(short, long)]
     screen: Option<String>,
 
+    /// Start on the screen which was open when the app last closed. Ignored
+    /// if --screen is set.
+    #[arg(short, long)]
+    resume: bool,
+
     /// Show build info
     #[arg(long)]
     build_info: bool,
@@ -26,11 +31,13 @@ pub fn parse_args() -> AppSettings {
         exit(0);
     }
 
+    let resume_last_screen = args.resume && args.screen.is_none();
     // manual default x_x
     let initial_screen = args.screen.unwrap_or("SplashScreen".into());
 
     AppSettings {
         initial_screen,
+        resume_last_screen,
         ..Default::default()
     }
 }
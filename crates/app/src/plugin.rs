@@ -7,6 +7,9 @@ use crate::prelude::*;
 pub struct AppSettings {
     pub initial_screen: String,
     pub use_physics: bool,
+    /// Restore the screen which was open when the app last closed, see
+    /// [ScreenPersistencePlugin].
+    pub resume_last_screen: bool,
 }
 #[allow(clippy::derivable_impls)]
 impl Default for AppSettings {
@@ -16,6 +19,7 @@ impl Default for AppSettings {
             Self {
                 initial_screen: Default::default(),
                 use_physics: true,
+                resume_last_screen: false,
             }
         }
         #[cfg(test)]
@@ -23,6 +27,7 @@ impl Default for AppSettings {
             Self {
                 initial_screen: Default::default(),
                 use_physics: false,
+                resume_last_screen: false,
             }
         }
     }
@@ -41,5 +46,9 @@ impl Plugin for AppPlugin {
         app.insert_resource(InitialScreen::from_name(
             self.settings.initial_screen.clone(),
         ));
+        #[cfg(feature = "dev")]
+        app.add_plugins(
+            ScreenPersistencePlugin::default().with_resume(self.settings.resume_last_screen),
+        );
     }
 }
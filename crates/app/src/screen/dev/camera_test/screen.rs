@@ -1,14 +1,8 @@
 use crate::prelude::*;
 
-#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect, Screen)]
+#[screen(loading = init, update = (update, camera_systems().take()))]
 pub struct CameraTestScreen;
-impl Screen for CameraTestScreen {
-    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
-        builder.add_systems(ScreenSchedule::Loading, init);
-        builder.add_systems(ScreenSchedule::Update, (update, camera_systems().take()));
-        builder
-    }
-}
 
 /// spawn the scene.
 /// this is temp, ideally load the scene from file
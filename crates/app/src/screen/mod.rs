@@ -4,7 +4,6 @@ use crate::prelude::*;
 mod dev;
 
 mod splash;
-mod util;
 mod world;
 
 pub mod prelude {
@@ -14,8 +13,6 @@ pub mod prelude {
     #[allow(unused_imports)] // TEMP
     #[cfg(feature = "dev")]
     pub use super::dev::prelude::*;
-
-    pub use super::util::*;
 }
 
 pub fn plugin(app: &mut App) {
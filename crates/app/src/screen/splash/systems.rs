@@ -1,9 +1,4 @@
 use crate::prelude::*;
 
-#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect, Screen)]
 pub struct SplashScreen;
-impl Screen for SplashScreen {
-    fn builder(builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
-        builder
-    }
-}
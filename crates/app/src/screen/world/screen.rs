@@ -1,19 +1,15 @@
 use crate::prelude::*;
 
-#[derive(AssetCollection, Resource, Default, Debug)]
-pub struct WorldAssets {
-    _player_assets: PlayerAssets,
-}
-
-#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect, Screen)]
+#[screen(on_ready = init, update = (player_systems().take(), tracking_cam_systems().take()))]
+#[screen(builder = Self::with_player)]
 pub struct WorldScreen;
-impl Screen for WorldScreen {
-    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
-        builder.add_systems(ScreenSchedule::Loading, init);
-        builder.add_systems(
-            ScreenSchedule::Update,
-            (player_systems().take(), tracking_cam_systems().take()),
-        );
+impl WorldScreen {
+    /// Asset collections and observers, which the attributes don't cover.
+    fn with_player(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder.load_assets::<PlayerAssets>();
+        builder.add_observer(spawn_player_root);
+        builder.add_observer(spawn_worldgen_root);
         builder
     }
 }
@@ -25,10 +21,5 @@ fn init(mut commands: Commands) {
 }
 
 pub fn plugin(app: &mut App) {
-    app.register_screen_loading_state::<WorldScreen>();
-    app.add_loading_state(
-        LoadingState::new(ScreenLoadingState::loading::<WorldScreen>())
-            .continue_to_state(ScreenLoadingState::ready::<WorldScreen>())
-            .load_collection::<WorldAssets>(),
-    );
+    app.register_screen::<WorldScreen>();
 }
@@ -11,6 +11,12 @@ struct ScreenCmd {
     list: bool,
     #[arg(short, long)]
     show: bool,
+    /// Go back to the previous screen
+    #[arg(short, long, conflicts_with = "target")]
+    back: bool,
+    /// Screen params as RON, e.g. `(level: 2)`
+    #[arg(short, long, requires = "target")]
+    params: Option<String>,
 }
 
 fn on_msg(
@@ -19,6 +25,7 @@ fn on_msg(
     screens: Screens,
     data: Res<ScreenData>,
     current_screen: Res<CurrentScreen>,
+    type_registry: Res<AppTypeRegistry>,
 ) {
     for msg in reader.read() {
         if msg.command.list {
@@ -34,10 +41,23 @@ fn on_msg(
             let screen = c!(screens.get_by_id(*screen));
             msg.println(&mut commands, screen.name().to_owned());
         }
+        if msg.command.back {
+            commands.trigger(go_back());
+        }
         if let Some(target) = msg.command.target.as_ref() {
             match screens.get_by_name(target) {
                 Ok(screen) => {
-                    commands.write_message(SwitchToScreenMsg(screen.screen_id()));
+                    let mut switch = SwitchToScreenMsg::new(screen.screen_id());
+                    if let Some(params) = msg.command.params.as_ref() {
+                        match screen.parse_params(&type_registry.read(), params) {
+                            Ok(params) => switch = switch.with_params(params),
+                            Err(err) => {
+                                msg.println(&mut commands, err.to_string());
+                                continue;
+                            }
+                        }
+                    }
+                    commands.write_message(switch);
                     msg.println(&mut commands, format!("Switching to {}", screen.name()));
                 }
                 Err(err) => {
@@ -47,7 +67,14 @@ fn on_msg(
         }
     }
 }
+fn complete_target(_: In<ConsoleCompleterInput>, data: Res<ScreenData>) -> Vec<String> {
+    data.iter()
+        .filter_map(|i| i.as_ref().map(|i| i.name().to_owned()))
+        .collect()
+}
+
 pub fn plugin(app: &mut App) {
     app.add_systems(PreUpdate, on_msg);
     app.add_console_command::<ScreenCmd>();
+    app.add_console_completer::<ScreenCmd, _>("target", complete_target);
 }
@@ -3,7 +3,7 @@ use bevy_tnua_avian3d::TnuaAvian3dSensorShape;
 use crate::prelude::*;
 
 // TODO: Split this out into a bundle
-fn spawn_player_root(
+pub fn spawn_player_root(
     _: On<SpawnPlayerRoot>,
     mut commands: Commands,
     player_assets: Res<PlayerAssets>,
@@ -57,5 +57,5 @@ fn on_move(trigger: On<Fire<PAMove>>, mut controller: Single<&mut PlayerControll
 }
 
 pub fn plugin(app: &mut App) {
-    app.add_observer(on_move).add_observer(spawn_player_root);
+    app.add_observer(on_move);
 }
@@ -6,6 +6,7 @@ use crate::prelude::*;
 
 pub mod prelude {
     pub use super::data::*;
+    pub use super::events::spawn_player_root;
     pub use super::systems::systems as player_systems;
 }
 
@@ -7,6 +7,7 @@ use data::*;
 
 pub mod prelude {
     pub use super::data::*;
+    pub use super::spawn_worldgen_root;
 }
 
 #[derive(Component, PartialEq, Eq, Debug, Default, Reflect)]
@@ -40,6 +41,5 @@ pub fn spawn_worldgen_root(
 pub fn plugin(app: &mut App) {
     app.register_type::<WorldgenRoot>()
         .init_resource::<WorldgenHandles>()
-        .register_type::<WorldgenHandles>()
-        .add_observer(spawn_worldgen_root);
+        .register_type::<WorldgenHandles>();
 }
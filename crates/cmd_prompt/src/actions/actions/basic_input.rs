@@ -6,10 +6,10 @@ pub fn delete_char(
     input: In<ConsoleActionSystemInput>,
     mut console_q: Query<&mut ConsoleInputText>,
 ) {
-    if let Ok(mut input) = console_q.get_mut(input.console_id) {
-        let popped = input.text.pop();
-        if let Some(popped) = popped {
-            input.move_cursor(-(popped.len_utf8() as isize));
+    if let Ok(mut input_text) = console_q.get_mut(input.console_id) {
+        match input.matched_logical_keys().next() {
+            Some(Key::Delete) => input_text.delete_forward(),
+            _ => input_text.delete_back(),
         }
     } else {
         error!(
@@ -22,10 +22,11 @@ pub fn delete_word(
     input: In<ConsoleActionSystemInput>,
     mut console_q: Query<&mut ConsoleInputText>,
 ) {
-    if let Ok(mut input) = console_q.get_mut(input.console_id) {
-        let last_ws = input.text.rfind(char::is_whitespace).unwrap_or_default();
-        input.text.truncate(last_ws);
-        input.set_cursor(last_ws);
+    if let Ok(mut input_text) = console_q.get_mut(input.console_id) {
+        match input.matched_logical_keys().next() {
+            Some(Key::Delete) => input_text.delete_word_forward(),
+            _ => input_text.delete_word_back(),
+        }
     } else {
         error!(
             "Could not delete word from console with id {}",
@@ -33,29 +34,47 @@ pub fn delete_word(
         );
     }
 }
+pub fn move_cursor(
+    input: In<ConsoleActionSystemInput>,
+    mut console_q: Query<&mut ConsoleInputText>,
+) {
+    if let Ok(mut input_text) = console_q.get_mut(input.console_id) {
+        let by_word = !input.matched_mods.is_empty();
+        match input.matched_logical_keys().next() {
+            Some(Key::ArrowLeft) if by_word => input_text.move_word_left(),
+            Some(Key::ArrowRight) if by_word => input_text.move_word_right(),
+            Some(Key::ArrowLeft) => input_text.move_left(),
+            Some(Key::ArrowRight) => input_text.move_right(),
+            Some(Key::Home) => input_text.move_home(),
+            Some(Key::End) => input_text.move_end(),
+            _ => {}
+        }
+    } else {
+        error!(
+            "Could not move cursor of console with id {}",
+            input.console_id
+        );
+    }
+}
 pub fn write_char(
     input: In<ConsoleActionSystemInput>,
     mut console_q: Query<&mut ConsoleInputText>,
+    key_codes: Res<ButtonInput<KeyCode>>,
     mut commands: Commands,
 ) {
+    // Ctrl chords are actions, but Windows reports AltGr as Ctrl+Alt, and
+    // AltGr types characters on many layouts.
+    let ctrl = key_codes.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let alt = key_codes.any_pressed([KeyCode::AltLeft, KeyCode::AltRight]);
+    if ctrl && !alt {
+        return;
+    }
     if let Ok(mut input_text) = console_q.get_mut(input.console_id) {
-        let pos = input_text.cursor();
         for key in input.matched_logical_keys() {
             match key {
-                Key::Character(c) => {
-                    input_text.text.insert_str(pos, c.as_str());
-                    input_text.move_cursor(c.len() as isize);
-                }
-                Key::Space => {
-                    input_text.text.insert(pos, ' ');
-                    input_text.move_cursor(1);
-                }
-                Key::Enter => {
-                    if input_text.cursor() == input_text.text.len() {
-                        input_text.text.insert(pos, '\n');
-                    }
-                    input_text.text.push('\n');
-                }
+                Key::Character(c) => input_text.insert(c.as_str()),
+                Key::Space => input_text.insert(" "),
+                Key::Enter => input_text.insert("\n"),
                 _ => {}
             }
         }
@@ -127,15 +146,25 @@ pub fn scroll_line(input: In<ConsoleActionSystemInput>, mut commands: Commands)
 
 pub(crate) fn plugin(app: &mut App) {
     app.register_console_action(
-        ConsoleActionKeybind::new(Key::Backspace)
+        ConsoleActionKeybind::new([Key::Backspace, Key::Delete])
             .without_modifiers([KeyCode::ControlLeft, KeyCode::ControlRight]),
         delete_char,
     );
     app.register_console_action(
-        ConsoleActionKeybind::new(Key::Backspace)
+        ConsoleActionKeybind::new([Key::Backspace, Key::Delete])
             .with_modifiers([KeyCode::ControlLeft, KeyCode::ControlRight]),
         delete_word,
     );
+    app.register_console_action(
+        ConsoleActionKeybind::new([Key::ArrowLeft, Key::ArrowRight, Key::Home, Key::End])
+            .with_modifiers([KeyCode::ControlLeft, KeyCode::ControlRight]),
+        move_cursor,
+    );
+    app.register_console_action(
+        ConsoleActionKeybind::new([Key::ArrowLeft, Key::ArrowRight, Key::Home, Key::End])
+            .without_modifiers([KeyCode::ControlLeft, KeyCode::ControlRight]),
+        move_cursor,
+    );
     app.register_console_action(
         ConsoleActionKeybind::new([ConsoleInput::AnyCharacter, Key::Space.into()]),
         write_char,
@@ -149,12 +178,72 @@ pub(crate) fn plugin(app: &mut App) {
     );
     app.register_console_action(
         ConsoleActionKeybind::new(Key::Character("l".into()))
-            .with_modifiers([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            .with_modifiers([KeyCode::ControlLeft, KeyCode::ControlRight])
+            .without_modifiers([KeyCode::AltLeft, KeyCode::AltRight]),
         clear,
     );
     app.register_console_action(
         ConsoleActionKeybind::new(Key::Character("c".into()))
-            .with_modifiers([KeyCode::ControlLeft, KeyCode::ControlRight]),
+            .with_modifiers([KeyCode::ControlLeft, KeyCode::ControlRight])
+            .without_modifiers([
+                KeyCode::ShiftLeft,
+                KeyCode::ShiftRight,
+                KeyCode::AltLeft,
+                KeyCode::AltRight,
+            ]),
         clear_input,
     );
 }
+
+#[cfg(test)]
+mod test {
+    use super::write_char;
+    use crate::prelude::*;
+    use crate::test_harness;
+    use bevy::input::{
+        ButtonState,
+        keyboard::{Key, KeyboardInput},
+    };
+
+    fn type_char(app: &mut App, console_id: Entity, c: &str) {
+        let input = ConsoleActionSystemInput {
+            console_id,
+            matched_input: vec![
+                KeyboardInput {
+                    key_code: KeyCode::KeyQ,
+                    logical_key: Key::Character(c.into()),
+                    state: ButtonState::Pressed,
+                    text: Some(c.into()),
+                    repeat: false,
+                    window: Entity::PLACEHOLDER,
+                }
+                .into(),
+            ],
+            matched_mods: vec![],
+        };
+        app.world_mut()
+            .run_system_cached_with(write_char, input)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_write_char_modifiers() {
+        let mut app = App::new();
+        app.add_plugins(test_harness::plugin);
+        let id = app.world_mut().spawn(Console).id();
+
+        type_char(&mut app, id, "a");
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::ControlLeft);
+        // A Ctrl chord is not text.
+        type_char(&mut app, id, "q");
+        // But AltGr, reported as Ctrl+Alt, is.
+        let mut keys = app.world_mut().resource_mut::<ButtonInput<KeyCode>>();
+        keys.press(KeyCode::AltRight);
+        type_char(&mut app, id, "@");
+        assert_eq!(
+            app.world().get::<ConsoleInputText>(id).unwrap().text(),
+            "a@"
+        );
+    }
+}
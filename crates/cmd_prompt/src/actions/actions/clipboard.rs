@@ -0,0 +1,118 @@
+use bevy::input::keyboard::Key;
+
+use crate::prelude::*;
+
+/// Copies the console's selection to the [ConsoleClipboard].
+pub fn copy(
+    input: In<ConsoleActionSystemInput>,
+    console_q: Query<(&ConsoleSelection, &ConsoleBuffer)>,
+    mut clipboard: ResMut<ConsoleClipboard>,
+) {
+    let (selection, buffer) = r!(console_q.get(input.console_id));
+    let text = rq!(selection.text(buffer));
+    if let Err(e) = clipboard.set_text(text) {
+        error!("{e}");
+    }
+}
+
+/// Pastes the [ConsoleClipboard] into the input at the cursor. Newlines are
+/// replaced with spaces, since the input is a single command.
+pub fn paste(
+    input: In<ConsoleActionSystemInput>,
+    mut console_q: Query<&mut ConsoleInputText>,
+    mut clipboard: ResMut<ConsoleClipboard>,
+    mut commands: Commands,
+) {
+    let mut input_text = r!(console_q.get_mut(input.console_id));
+    match clipboard.get_text() {
+        Ok(text) => {
+            let text = text
+                .trim_end_matches(['\r', '\n'])
+                .replace(['\r', '\n'], " ");
+            input_text.insert(&text);
+            commands.write_message(ConsoleViewMsg::jump_to_bottom(input.console_id));
+        }
+        Err(ClipboardError::Empty) => {}
+        Err(e) => error!("{e}"),
+    }
+}
+
+pub fn plugin(app: &mut App) {
+    app.register_console_action(
+        ConsoleActionKeybind::new([Key::Character("c".into()), Key::Character("C".into())])
+            .with_modifiers((
+                [KeyCode::ControlLeft, KeyCode::ControlRight],
+                [KeyCode::ShiftLeft, KeyCode::ShiftRight],
+            ))
+            .without_modifiers([KeyCode::AltLeft, KeyCode::AltRight]),
+        copy,
+    );
+    app.register_console_action(
+        ConsoleActionKeybind::new([Key::Character("v".into()), Key::Character("V".into())])
+            .with_modifiers([KeyCode::ControlLeft, KeyCode::ControlRight])
+            .without_modifiers([KeyCode::AltLeft, KeyCode::AltRight]),
+        paste,
+    );
+}
+
+#[cfg(test)]
+mod test {
+    use super::{copy, paste};
+    use crate::prelude::*;
+    use crate::test_harness;
+
+    fn run_action<M>(
+        app: &mut App,
+        console_id: Entity,
+        action: impl IntoConsoleActionSystem<M> + 'static,
+    ) {
+        let input = ConsoleActionSystemInput {
+            console_id,
+            matched_input: vec![],
+            matched_mods: vec![],
+        };
+        app.world_mut()
+            .run_system_cached_with(action, input)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_copy_paste() {
+        let mut app = App::new();
+        app.add_plugins(test_harness::plugin);
+        app.insert_resource(ConsoleClipboard::new(MemoryClipboard::default()));
+        let id = app.world_mut().spawn(Console).id();
+
+        // Nothing to paste yet.
+        run_action(&mut app, id, paste);
+        let world = app.world_mut();
+        assert_eq!(world.get::<ConsoleInputText>(id).unwrap().text(), "");
+
+        world
+            .get_mut::<ConsoleBuffer>(id)
+            .unwrap()
+            .write("hello world\n")
+            .unwrap();
+        *world.get_mut::<ConsoleSelection>(id).unwrap() = ConsoleSelection {
+            anchor: Some(SelectionPoint { line: 0, index: 6 }),
+            head: Some(SelectionPoint { line: 0, index: 11 }),
+        };
+        run_action(&mut app, id, copy);
+        let world = app.world_mut();
+        assert_eq!(
+            world.resource_mut::<ConsoleClipboard>().get_text().unwrap(),
+            "world"
+        );
+
+        let mut input = world.get_mut::<ConsoleInputText>(id).unwrap();
+        input.insert("echo ");
+        input.insert("!");
+        input.move_left();
+        run_action(&mut app, id, paste);
+        let world = app.world_mut();
+        assert_eq!(
+            world.get::<ConsoleInputText>(id).unwrap().text(),
+            "echo world!"
+        );
+    }
+}
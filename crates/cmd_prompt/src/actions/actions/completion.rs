@@ -0,0 +1,189 @@
+use bevy::input::keyboard::Key;
+
+use crate::prelude::*;
+
+/// The state of an ambiguous completion, kept on the console so that
+/// repeatedly pressing Tab cycles through the candidates.
+#[derive(Component, Debug, Reflect, Default, Clone)]
+pub struct ConsoleCompletion {
+    candidates: Vec<String>,
+    index: Option<usize>,
+    word_start: usize,
+    /// The input as left by the last completion. Any other edit starts over.
+    text: String,
+}
+
+/// Completes the word under the cursor. A single candidate is inserted
+/// directly. Otherwise the word is extended to the candidates' common prefix
+/// and the candidates are printed, and pressing Tab again cycles through them.
+pub fn complete(input: In<ConsoleActionSystemInput>, world: &mut World) {
+    let console_id = input.console_id;
+    let Some((text, cursor)) = world
+        .get::<ConsoleInputText>(console_id)
+        .map(|input| (input.text.clone(), input.cursor()))
+    else {
+        error!("Could not complete input of console with id {console_id}");
+        return;
+    };
+    let mut state = world
+        .get::<ConsoleCompletion>(console_id)
+        .cloned()
+        .unwrap_or_default();
+
+    let replacement = if !state.candidates.is_empty() && state.text == text {
+        let index = state.index.map_or(0, |i| (i + 1) % state.candidates.len());
+        state.index = Some(index);
+        quote(&state.candidates[index])
+    } else {
+        let word_start = text[..cursor]
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(0, |(i, c)| i + c.len_utf8());
+        let partial = &text[word_start..cursor];
+        let Some(args) = shlex::split(&text[..word_start]) else {
+            return;
+        };
+        let candidates = complete_word(world, console_id, &args, partial);
+        state = ConsoleCompletion {
+            word_start,
+            ..default()
+        };
+        match candidates.len() {
+            0 => return,
+            1 => format!("{} ", quote(&candidates[0])),
+            _ => {
+                world.write_message(ConsoleWriteMsg {
+                    message: format!("\n{}\n", candidates.join("  ")),
+                    console_id,
+                });
+                let prefix = common_prefix(&candidates);
+                let replacement =
+                    if prefix.len() > partial.len() && !prefix.contains(char::is_whitespace) {
+                        prefix.to_owned()
+                    } else {
+                        partial.to_owned()
+                    };
+                state.candidates = candidates;
+                replacement
+            }
+        }
+    };
+
+    let Some(mut input_text) = world.get_mut::<ConsoleInputText>(console_id) else {
+        return;
+    };
+    input_text
+        .text
+        .replace_range(state.word_start..cursor, &replacement);
+    input_text.set_cursor(state.word_start + replacement.len());
+    state.text = input_text.text.clone();
+    world.entity_mut(console_id).insert(state);
+    world.write_message(ConsoleViewMsg::jump_to_bottom(console_id));
+}
+
+/// Gathers the static and dynamic candidates which start with `partial`.
+fn complete_word(
+    world: &mut World,
+    console_id: Entity,
+    args: &[String],
+    partial: &str,
+) -> Vec<String> {
+    let completions = world
+        .resource::<ConsoleCommands>()
+        .completions(args, partial);
+    let mut candidates = completions.values;
+    if let Some(key) = completions.argument
+        && let Some(completer) = world.resource::<ConsoleCompleters>().get(&key).copied()
+    {
+        let input = ConsoleCompleterInput {
+            console_id,
+            partial: partial.to_owned(),
+        };
+        match world.run_system_with(completer, input) {
+            Ok(values) => candidates.extend(values),
+            Err(e) => error!("Console completer for {key:?} failed: {e}"),
+        }
+    }
+    candidates.retain(|candidate| candidate.starts_with(partial));
+    candidates.sort();
+    candidates.dedup();
+    candidates
+}
+
+fn common_prefix(candidates: &[String]) -> &str {
+    let first = &candidates[0];
+    let len = candidates[1..].iter().fold(first.len(), |len, candidate| {
+        first[..len]
+            .char_indices()
+            .zip(candidate.chars())
+            .find(|((_, a), b)| a != b)
+            .map_or(len.min(candidate.len()), |((i, _), _)| i)
+    });
+    &first[..len]
+}
+
+fn quote(candidate: &str) -> String {
+    shlex::try_quote(candidate).map_or_else(|_| candidate.to_owned(), |quoted| quoted.into_owned())
+}
+
+pub fn plugin(app: &mut App) {
+    app.register_console_action(ConsoleActionKeybind::new(Key::Tab), complete);
+}
+
+#[cfg(test)]
+mod test {
+    use super::complete;
+    use crate::prelude::*;
+    use crate::test_harness;
+    use clap::Parser;
+
+    #[derive(Parser, Message, Clone)]
+    #[command(name = "greet")]
+    struct GreetCmd {
+        name: String,
+    }
+
+    /// Optionally sets the input, then completes it and returns the result.
+    fn press_tab(app: &mut App, console_id: Entity, value: Option<&str>) -> String {
+        let world = app.world_mut();
+        if let Some(value) = value {
+            let mut input = world.get_mut::<ConsoleInputText>(console_id).unwrap();
+            input.text = value.to_string();
+            input.set_cursor(value.len());
+        }
+        let input = ConsoleActionSystemInput {
+            console_id,
+            matched_input: vec![],
+            matched_mods: vec![],
+        };
+        world.run_system_cached_with(complete, input).unwrap();
+        world
+            .get::<ConsoleInputText>(console_id)
+            .unwrap()
+            .text
+            .clone()
+    }
+
+    #[test]
+    fn test_completion() {
+        let mut app = App::new();
+        app.add_plugins(test_harness::plugin);
+        app.add_console_command::<GreetCmd>();
+        app.add_console_completer::<GreetCmd, _>("name", |_: In<ConsoleCompleterInput>| {
+            vec!["world".to_string(), "wombat".to_string()]
+        });
+        let id = app.world_mut().spawn(Console).id();
+
+        assert_eq!(press_tab(&mut app, id, Some("sh")), "show ");
+        // Ambiguous, so extends to the common prefix and then cycles.
+        assert_eq!(press_tab(&mut app, id, Some("show co")), "show com");
+        assert_eq!(press_tab(&mut app, id, None), "show commands");
+        assert_eq!(press_tab(&mut app, id, None), "show components");
+        assert_eq!(press_tab(&mut app, id, None), "show commands");
+        // Editing the input starts over.
+        assert_eq!(press_tab(&mut app, id, Some("show -")), "show -");
+        assert_eq!(press_tab(&mut app, id, Some("greet wor")), "greet world ");
+        assert_eq!(press_tab(&mut app, id, Some("greet x")), "greet x");
+    }
+}
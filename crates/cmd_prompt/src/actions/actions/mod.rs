@@ -1,8 +1,15 @@
 use crate::prelude::*;
 
 pub mod basic_input;
+pub mod clipboard;
+pub mod completion;
 pub mod history;
 
 pub fn plugin(app: &mut App) {
-    app.add_plugins((history::plugin, basic_input::plugin));
+    app.add_plugins((
+        history::plugin,
+        basic_input::plugin,
+        clipboard::plugin,
+        completion::plugin,
+    ));
 }
@@ -0,0 +1,93 @@
+//! Clipboard access for copying the console's selection and pasting into its
+//! input. The backend is swappable, so the console can be used (and tested)
+//! without a system clipboard.
+
+use crate::prelude::*;
+
+#[derive(Debug, thiserror::Error)]
+pub enum ClipboardError {
+    #[error("The clipboard does not contain any text.")]
+    Empty,
+    #[error("Could not access the clipboard: {0}")]
+    Backend(String),
+}
+
+/// Somewhere to copy text to and paste it from.
+pub trait ClipboardBackend: Send + Sync + 'static {
+    fn get_text(&mut self) -> Result<String, ClipboardError>;
+    fn set_text(&mut self, text: String) -> Result<(), ClipboardError>;
+}
+
+/// A clipboard which only lives as long as the app. Used when the system
+/// clipboard is unavailable, and in tests.
+#[derive(Debug, Default, Clone)]
+pub struct MemoryClipboard(Option<String>);
+impl ClipboardBackend for MemoryClipboard {
+    fn get_text(&mut self) -> Result<String, ClipboardError> {
+        self.0.clone().ok_or(ClipboardError::Empty)
+    }
+    fn set_text(&mut self, text: String) -> Result<(), ClipboardError> {
+        self.0 = Some(text);
+        Ok(())
+    }
+}
+
+/// The operating system's clipboard. Requires the `system_clipboard` feature.
+#[cfg(feature = "system_clipboard")]
+pub struct SystemClipboard(std::sync::Mutex<arboard::Clipboard>);
+#[cfg(feature = "system_clipboard")]
+impl SystemClipboard {
+    pub fn new() -> Result<Self, ClipboardError> {
+        arboard::Clipboard::new()
+            .map(|clipboard| Self(std::sync::Mutex::new(clipboard)))
+            .map_err(|e| ClipboardError::Backend(e.to_string()))
+    }
+}
+#[cfg(feature = "system_clipboard")]
+impl ClipboardBackend for SystemClipboard {
+    fn get_text(&mut self) -> Result<String, ClipboardError> {
+        let clipboard = self
+            .0
+            .get_mut()
+            .map_err(|e| ClipboardError::Backend(e.to_string()))?;
+        clipboard.get_text().map_err(|e| match e {
+            arboard::Error::ContentNotAvailable => ClipboardError::Empty,
+            e => ClipboardError::Backend(e.to_string()),
+        })
+    }
+    fn set_text(&mut self, text: String) -> Result<(), ClipboardError> {
+        let clipboard = self
+            .0
+            .get_mut()
+            .map_err(|e| ClipboardError::Backend(e.to_string()))?;
+        clipboard
+            .set_text(text)
+            .map_err(|e| ClipboardError::Backend(e.to_string()))
+    }
+}
+
+/// The clipboard used by the console's copy and paste actions. Defaults to
+/// the system clipboard if the `system_clipboard` feature is enabled and it
+/// is available, and to a [MemoryClipboard] otherwise. Insert this resource
+/// to use another backend.
+#[derive(Resource, Deref, DerefMut)]
+pub struct ConsoleClipboard(pub Box<dyn ClipboardBackend>);
+impl ConsoleClipboard {
+    pub fn new(backend: impl ClipboardBackend) -> Self {
+        Self(Box::new(backend))
+    }
+}
+impl Default for ConsoleClipboard {
+    fn default() -> Self {
+        #[cfg(feature = "system_clipboard")]
+        match SystemClipboard::new() {
+            Ok(clipboard) => return Self::new(clipboard),
+            Err(e) => warn!("{e}. Falling back to an in-memory clipboard."),
+        }
+        Self::new(MemoryClipboard::default())
+    }
+}
+
+pub(crate) fn plugin(app: &mut App) {
+    app.init_resource::<ConsoleClipboard>();
+}
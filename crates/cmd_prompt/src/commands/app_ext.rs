@@ -6,6 +6,14 @@ impl<T> ConsoleCommand for T where T: clap::Parser + Send + Sync + 'static {}
 pub trait CommandExt {
     /// Registers a console command to the application.
     fn add_console_command<T: ConsoleCommand>(&mut self) -> &mut Self;
+    /// Registers a dynamic completer for the argument `arg` of the console
+    /// command `T`. `arg` is the clap argument id, i.e. the field name. The
+    /// completer's candidates are offered alongside any enumerated values.
+    fn add_console_completer<T: ConsoleCommand, M>(
+        &mut self,
+        arg: impl Into<String>,
+        completer: impl IntoConsoleCompleter<M> + 'static,
+    ) -> &mut Self;
 }
 impl CommandExt for App {
     // TODO: If a command is not initialized, it will panic.
@@ -19,6 +27,19 @@ impl CommandExt for App {
         cmds.insert(name.to_string(), ConcreteConsoleCommand { cmd, dispatch });
         self
     }
+    fn add_console_completer<T: ConsoleCommand, M>(
+        &mut self,
+        arg: impl Into<String>,
+        completer: impl IntoConsoleCompleter<M> + 'static,
+    ) -> &mut Self {
+        self.world_mut().init_resource::<ConsoleCompleters>();
+        let name = T::command().get_name().to_string();
+        let completer = self.world_mut().register_system(completer);
+        self.world_mut()
+            .resource_mut::<ConsoleCompleters>()
+            .insert((name, arg.into()), completer);
+        self
+    }
 }
 
 fn dispatch_cmd<T: ConsoleCommand>(
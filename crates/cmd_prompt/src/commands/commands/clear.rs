@@ -8,11 +8,16 @@ struct ClearCmd;
 
 pub fn clear_buffer(
     id: In<Entity>,
-    mut console_q: Query<(&mut ConsoleBuffer, &mut ConsoleInputText)>,
+    mut console_q: Query<(
+        &mut ConsoleBuffer,
+        &mut ConsoleInputText,
+        &mut ConsoleSelection,
+    )>,
     mut commands: Commands,
 ) {
-    let (mut buffer, mut input_text) = console_q.get_mut(*id).unwrap();
+    let (mut buffer, mut input_text, mut selection) = console_q.get_mut(*id).unwrap();
     buffer.clear();
+    selection.clear();
     commands.write_message(ConsoleViewMsg::jump_to_bottom(*id));
     input_text.text.clear();
     input_text.anchor = 0;
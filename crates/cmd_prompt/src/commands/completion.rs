@@ -0,0 +1,229 @@
+//! Tab completion for console commands. Command names, subcommands, flags and
+//! enumerated values come from the stored [clap::Command]s. Anything else can
+//! be completed with a dynamic completer, see
+//! [CommandExt::add_console_completer].
+
+use crate::prelude::*;
+use bevy::{ecs::system::SystemId, platform::collections::HashMap};
+use clap::{Arg, Command};
+
+pub trait IntoConsoleCompleter<M>: IntoSystem<In<ConsoleCompleterInput>, Vec<String>, M> {}
+impl<T, M> IntoConsoleCompleter<M> for T where
+    T: IntoSystem<In<ConsoleCompleterInput>, Vec<String>, M>
+{
+}
+
+pub type ConsoleCompleter = SystemId<In<ConsoleCompleterInput>, Vec<String>>;
+
+#[derive(Debug, Clone)]
+pub struct ConsoleCompleterInput {
+    pub console_id: Entity,
+    /// The word being completed, up to the cursor. Candidates which do not
+    /// start with it are filtered out afterwards, so completers may ignore it.
+    pub partial: String,
+}
+
+/// Stores the dynamic completers, keyed by command name and argument id.
+#[derive(Resource, Debug, Deref, DerefMut, Default, Clone, Reflect)]
+#[reflect(Resource)]
+pub struct ConsoleCompleters(#[reflect(ignore)] HashMap<(String, String), ConsoleCompleter>);
+
+/// The candidates for the word under the cursor, before filtering.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct CompletionCandidates {
+    pub values: Vec<String>,
+    /// The command name and argument id whose value is being completed, used
+    /// to look up a dynamic completer.
+    pub argument: Option<(String, String)>,
+}
+
+impl ConsoleCommands {
+    /// Lists the candidates for the word `partial`, given the words before it.
+    /// The candidates are not filtered by `partial`.
+    pub fn completions(&self, args: &[String], partial: &str) -> CompletionCandidates {
+        let Some((name, args)) = args.split_first() else {
+            return CompletionCandidates {
+                values: self.keys().cloned().collect(),
+                argument: None,
+            };
+        };
+        let Some(cmd) = self.get(name) else {
+            return default();
+        };
+        // Building propagates global args and adds the help flags.
+        let mut cmd = cmd.cmd.clone();
+        cmd.build();
+        let (values, arg) = complete_command(&cmd, args, partial);
+        CompletionCandidates {
+            values,
+            argument: arg.map(|arg| (name.clone(), arg)),
+        }
+    }
+}
+
+/// Walks the words already typed to find what `partial` fills in. Returns the
+/// static candidates and the id of the argument being completed, if any.
+fn complete_command(
+    cmd: &Command,
+    args: &[String],
+    partial: &str,
+) -> (Vec<String>, Option<String>) {
+    let mut cmd = cmd;
+    let mut positionals = 0;
+    let mut pending: Option<&Arg> = None;
+    let mut escaped = false;
+    for word in args {
+        if pending.take().is_some() {
+            continue;
+        }
+        if escaped {
+            positionals += 1;
+        } else if word == "--" {
+            escaped = true;
+        } else if let Some(long) = word.strip_prefix("--") {
+            pending = (!long.contains('='))
+                .then(|| find_long(cmd, long))
+                .flatten()
+                .filter(|arg| arg.get_action().takes_values());
+        } else if let Some(shorts) = word.strip_prefix('-')
+            && !shorts.is_empty()
+        {
+            pending = find_pending_short(cmd, shorts);
+        } else if let Some(sub) = cmd.find_subcommand(word) {
+            cmd = sub;
+            positionals = 0;
+        } else {
+            positionals += 1;
+        }
+    }
+
+    if let Some(arg) = pending {
+        return (possible_values(arg), Some(arg.get_id().to_string()));
+    }
+    if !escaped && partial.starts_with('-') {
+        let flags = cmd
+            .get_arguments()
+            .filter(|arg| !arg.is_positional() && !arg.is_hide_set())
+            .flat_map(|arg| {
+                let long = arg.get_long().map(|long| format!("--{long}"));
+                let short = arg.get_short().map(|short| format!("-{short}"));
+                long.into_iter().chain(short)
+            })
+            .collect();
+        return (flags, None);
+    }
+    let mut values = if escaped {
+        vec![]
+    } else {
+        cmd.get_subcommands()
+            .filter(|sub| !sub.is_hide_set())
+            .map(|sub| sub.get_name().to_owned())
+            .collect::<Vec<_>>()
+    };
+    let positional = cmd.get_positionals().nth(positionals).or_else(|| {
+        cmd.get_positionals()
+            .last()
+            .filter(|arg| arg.get_num_args().is_some_and(|n| n.max_values() > 1))
+    });
+    let Some(arg) = positional else {
+        return (values, None);
+    };
+    values.extend(possible_values(arg));
+    (values, Some(arg.get_id().to_string()))
+}
+
+fn find_long<'a>(cmd: &'a Command, long: &str) -> Option<&'a Arg> {
+    cmd.get_arguments().find(|arg| arg.get_long() == Some(long))
+}
+
+/// Finds the option in a group of short flags, e.g. `-ef`, which still needs
+/// its value in the next word.
+fn find_pending_short<'a>(cmd: &'a Command, shorts: &str) -> Option<&'a Arg> {
+    for (i, short) in shorts.char_indices() {
+        let arg = cmd
+            .get_arguments()
+            .find(|arg| arg.get_short() == Some(short))?;
+        if arg.get_action().takes_values() {
+            // `-fvalue` carries its own value.
+            return (i + short.len_utf8() == shorts.len()).then_some(arg);
+        }
+    }
+    None
+}
+
+fn possible_values(arg: &Arg) -> Vec<String> {
+    arg.get_possible_values()
+        .iter()
+        .filter(|value| !value.is_hide_set())
+        .map(|value| value.get_name().to_owned())
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::prelude::*;
+    use crate::test_harness;
+
+    /// Only one app per test can capture logs, so tests share one.
+    fn get_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(test_harness::plugin);
+        app
+    }
+
+    fn completions(app: &App, args: &[&str], partial: &str) -> CompletionCandidates {
+        let args = args.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+        let mut candidates = app
+            .world()
+            .resource::<ConsoleCommands>()
+            .completions(&args, partial);
+        candidates.values.sort();
+        candidates
+    }
+
+    #[test]
+    fn test_command_names() {
+        let app = get_app();
+        let candidates = completions(&app, &[], "");
+        assert!(candidates.values.contains(&"show".to_string()));
+        assert!(candidates.values.contains(&"clear".to_string()));
+        assert_eq!(candidates.argument, None);
+    }
+
+    #[test]
+    fn test_flags() {
+        let app = get_app();
+        let candidates = completions(&app, &["show"], "-");
+        for flag in ["--filter", "-f", "-e", "--help", "-h"] {
+            assert!(candidates.values.contains(&flag.to_string()), "{flag}");
+        }
+    }
+
+    #[test]
+    fn test_value_enum() {
+        let app = get_app();
+        let candidates = completions(&app, &["show"], "co");
+        assert_eq!(
+            candidates.values,
+            ["archetypes", "commands", "components", "resources", "types"]
+        );
+        assert_eq!(
+            candidates.argument,
+            Some(("show".to_string(), "kind".to_string()))
+        );
+        // The option's value, then the positional again.
+        let candidates = completions(&app, &["show", "-f"], "");
+        assert_eq!(
+            candidates.argument,
+            Some(("show".to_string(), "filter".to_string()))
+        );
+        let candidates = completions(&app, &["show", "-f", "x"], "");
+        assert_eq!(
+            candidates.argument,
+            Some(("show".to_string(), "kind".to_string()))
+        );
+        // Nothing left to complete.
+        let candidates = completions(&app, &["show", "types"], "");
+        assert_eq!(candidates, CompletionCandidates::default());
+    }
+}
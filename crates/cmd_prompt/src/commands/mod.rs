@@ -6,16 +6,19 @@ use crate::prelude::*;
 mod app_ext;
 #[allow(clippy::module_inception)]
 mod commands;
+mod completion;
 mod data;
 mod events;
 
 pub mod prelude {
     pub use super::app_ext::*;
     pub use super::commands::prelude::*;
+    pub use super::completion::*;
     pub use super::data::*;
 }
 
 pub fn plugin(app: &mut App) {
     app.init_resource::<ConsoleCommands>();
+    app.init_resource::<ConsoleCompleters>();
     app.add_plugins((events::plugin, commands::plugin));
 }
@@ -1,5 +1,6 @@
 mod actions;
 mod assets;
+mod clipboard;
 mod commands;
 mod systems;
 #[cfg(test)]
@@ -10,6 +11,11 @@ pub mod prelude {
     pub use super::ConsolePlugin;
     pub use super::actions::prelude::*;
     pub use super::assets::*;
+    #[cfg(feature = "system_clipboard")]
+    pub use super::clipboard::SystemClipboard;
+    pub use super::clipboard::{
+        ClipboardBackend, ClipboardError, ConsoleClipboard, MemoryClipboard,
+    };
     pub use super::commands::prelude::*;
     pub use super::systems::*;
     pub use super::ui::prelude::*;
@@ -29,6 +35,7 @@ impl Plugin for ConsolePlugin {
             crate::commands::plugin,
             crate::actions::plugin,
             crate::assets::plugin,
+            crate::clipboard::plugin,
         ));
         app.add_systems(
             PostUpdate,
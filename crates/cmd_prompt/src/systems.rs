@@ -76,11 +76,16 @@ pub fn clear_action_queue(mut reader: MessageReader<ConsoleActionMsg>, mut comma
 
 pub fn clear_write_queue(
     mut reader: MessageReader<ConsoleWriteMsg>,
-    mut buffer_q: Query<(&mut ConsoleBuffer, &mut ConsoleInputText)>,
+    mut buffer_q: Query<(
+        &mut ConsoleBuffer,
+        &mut ConsoleInputText,
+        &mut ConsoleSelection,
+    )>,
 ) {
     for item in reader.read() {
-        let (mut buffer, mut input) = c!(buffer_q.get_mut(item.console_id));
-        c!(buffer.write(&item.message));
+        let (mut buffer, mut input, mut selection) = c!(buffer_q.get_mut(item.console_id));
+        let overwritten = c!(buffer.write(&item.message));
+        selection.evict(&overwritten);
         input.anchor = buffer.reset_write_anchor();
     }
 }
@@ -49,19 +49,13 @@ impl ConsoleBuffer {
         }
         let mut overwritten: String = val.chars().filter_map(|c| self.push_overwrite(c)).collect();
 
-        if !overwritten.is_empty() {
-            overwritten += self
+        if !overwritten.is_empty() && !overwritten.ends_with('\n') {
+            let end = self
                 .0
                 .iter()
-                .take_while(|c| **c != '\n')
-                .collect::<String>()
-                .as_str();
-            let ok = self.0.pop_front().filter(|c| *c == '\n').is_some();
-            if ok {
-                overwritten += "\n"
-            } else {
-                return Err(ConsoleBufferError::WriteError);
-            }
+                .position(|c| *c == '\n')
+                .ok_or(ConsoleBufferError::WriteError)?;
+            overwritten.extend(self.0.drain(..=end));
         }
         Ok(overwritten)
     }
@@ -13,6 +13,7 @@ use bevy::{
     ConsoleBufferFlags,
     ConsolePrompt,
     ConsoleInputText,
+    ConsoleSelection,
     TextFont,
     ConsoleAssetHandle<ConsoleEnvVars>,
     ConsoleAssetHandle<ConsoleHistory>,
@@ -41,8 +42,11 @@ impl Console {
             .commands()
             .entity(ctx.entity)
             .insert(bundle)
+            .with_child(ConsoleCaret)
             .observe(Self::on_click)
-            .observe(Self::on_scroll);
+            .observe(Self::on_scroll)
+            .observe(ConsoleSelection::on_press)
+            .observe(ConsoleSelection::on_drag);
     }
     fn on_click(trigger: On<Pointer<Click>>, mut focus: ResMut<InputFocus>) {
         focus.set(trigger.entity);
@@ -65,6 +69,10 @@ pub struct ConsoleInputText {
     pub(crate) anchor: usize,
 }
 impl ConsoleInputText {
+    /// The current input.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
     pub fn set_cursor(&mut self, pos: usize) {
         self.cursor = self.text.ceil_char_boundary(pos);
     }
@@ -77,15 +85,174 @@ impl ConsoleInputText {
     pub fn cursor(&self) -> usize {
         self.text.ceil_char_boundary(self.cursor)
     }
+
+    /// Inserts the string at the cursor and moves the cursor past it.
+    pub fn insert(&mut self, val: &str) {
+        let pos = self.cursor();
+        self.text.insert_str(pos, val);
+        self.cursor = pos + val.len();
+    }
+    /// Deletes the character before the cursor.
+    pub fn delete_back(&mut self) {
+        self.delete_to(self.prev_char());
+    }
+    /// Deletes the character after the cursor.
+    pub fn delete_forward(&mut self) {
+        self.delete_to(self.next_char());
+    }
+    /// Deletes back to the start of the current or previous word.
+    pub fn delete_word_back(&mut self) {
+        self.delete_to(self.prev_word());
+    }
+    /// Deletes up to the end of the current or next word.
+    pub fn delete_word_forward(&mut self) {
+        self.delete_to(self.next_word());
+    }
+
+    pub fn move_left(&mut self) {
+        self.cursor = self.prev_char();
+    }
+    pub fn move_right(&mut self) {
+        self.cursor = self.next_char();
+    }
+    /// Moves to the start of the current or previous word.
+    pub fn move_word_left(&mut self) {
+        self.cursor = self.prev_word();
+    }
+    /// Moves to the end of the current or next word.
+    pub fn move_word_right(&mut self) {
+        self.cursor = self.next_word();
+    }
+    pub fn move_home(&mut self) {
+        self.cursor = 0;
+    }
+    pub fn move_end(&mut self) {
+        self.cursor = self.text.len();
+    }
+
+    /// Deletes the text between the cursor and `pos`, leaving the cursor at
+    /// the start of the deleted range.
+    fn delete_to(&mut self, pos: usize) {
+        let cursor = self.cursor();
+        let range = cursor.min(pos)..cursor.max(pos);
+        self.cursor = range.start;
+        self.text.replace_range(range, "");
+    }
+    fn prev_char(&self) -> usize {
+        self.text[..self.cursor()]
+            .char_indices()
+            .next_back()
+            .map_or(0, |(i, _)| i)
+    }
+    fn next_char(&self) -> usize {
+        let cursor = self.cursor();
+        self.text[cursor..]
+            .chars()
+            .next()
+            .map_or(cursor, |c| cursor + c.len_utf8())
+    }
+    fn prev_word(&self) -> usize {
+        self.text[..self.cursor()]
+            .trim_end()
+            .char_indices()
+            .rev()
+            .find(|(_, c)| c.is_whitespace())
+            .map_or(0, |(i, c)| i + c.len_utf8())
+    }
+    fn next_word(&self) -> usize {
+        let cursor = self.cursor();
+        let rest = &self.text[cursor..];
+        let word = rest.trim_start();
+        let word_start = cursor + rest.len() - word.len();
+        word_start + word.find(char::is_whitespace).unwrap_or(word.len())
+    }
 }
 
 pub fn update_console_input_text(
-    q: Query<(&mut ConsoleBuffer, &ConsoleInputText, &ConsolePrompt), Changed<ConsoleInputText>>,
+    q: Query<
+        (
+            &mut ConsoleBuffer,
+            &mut ComputedConsoleTextBlock,
+            &ConsoleInputText,
+            &ConsolePrompt,
+            &mut ConsoleSelection,
+        ),
+        Changed<ConsoleInputText>,
+    >,
 ) {
-    for (mut buffer, input, prompt) in q {
-        buffer.write_at(input.anchor, &prompt.0).unwrap();
-        buffer
+    for (mut buffer, mut block, input, prompt, mut selection) in q {
+        // Also moves the caret when only the cursor changed.
+        block.trigger_rerender();
+        let (_, overwritten) = buffer.write_at(input.anchor, &prompt.0).unwrap();
+        selection.evict(&overwritten);
+        let (_, overwritten) = buffer
             .write_at(input.anchor + prompt.0.len(), &input.text)
             .unwrap();
+        selection.evict(&overwritten);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::ConsoleInputText;
+
+    fn input(text: &str, cursor: usize) -> ConsoleInputText {
+        let mut input = ConsoleInputText {
+            text: text.into(),
+            ..Default::default()
+        };
+        input.set_cursor(cursor);
+        input
+    }
+
+    #[test]
+    fn test_movement() {
+        let mut input = input("echo  héllo world", 0);
+        input.move_right();
+        assert_eq!(input.cursor(), 1);
+        input.move_word_right();
+        assert_eq!(input.cursor(), 4);
+        input.move_word_right();
+        assert_eq!(input.cursor(), 12);
+        for _ in 0..4 {
+            input.move_left();
+        }
+        // Steps over the multibyte character.
+        assert_eq!(input.cursor(), 7);
+        input.move_word_left();
+        assert_eq!(input.cursor(), 6);
+        input.move_word_left();
+        assert_eq!(input.cursor(), 0);
+        input.move_end();
+        assert_eq!(input.cursor(), input.text().len());
+        input.move_right();
+        assert_eq!(input.cursor(), input.text().len());
+        input.move_home();
+        input.move_left();
+        assert_eq!(input.cursor(), 0);
+    }
+
+    #[test]
+    fn test_editing() {
+        let mut input = input("echo world", 5);
+        input.insert("hello ");
+        assert_eq!(input.text(), "echo hello world");
+        assert_eq!(input.cursor(), 11);
+        input.delete_back();
+        assert_eq!(input.text(), "echo helloworld");
+        input.delete_forward();
+        assert_eq!(input.text(), "echo helloorld");
+        assert_eq!(input.cursor(), 10);
+        input.delete_word_back();
+        assert_eq!(input.text(), "echo orld");
+        assert_eq!(input.cursor(), 5);
+        input.delete_word_forward();
+        assert_eq!(input.text(), "echo ");
+        input.move_home();
+        input.delete_back();
+        assert_eq!(input.text(), "echo ");
+        input.delete_word_forward();
+        assert_eq!(input.text(), " ");
+        assert_eq!(input.cursor(), 0);
     }
 }
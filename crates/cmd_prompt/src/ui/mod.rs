@@ -7,6 +7,7 @@ use crate::prelude::*;
 mod buffer;
 mod console;
 mod data;
+mod selection;
 mod text;
 mod view;
 
@@ -14,6 +15,7 @@ pub mod prelude {
     pub use super::buffer::*;
     pub use super::console::*;
     pub use super::data::*;
+    pub use super::selection::*;
     pub use super::text::prelude::*;
     pub use super::view::*;
 }
@@ -0,0 +1,261 @@
+//! Mouse selection of the console's output.
+
+use crate::prelude::*;
+
+/// A position in the [ConsoleBuffer]. `line` counts the buffer's lines from
+/// the top, and `index` is a byte offset into that line.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Reflect)]
+pub struct SelectionPoint {
+    pub line: usize,
+    pub index: usize,
+}
+
+/// The console's selected text, set by dragging the mouse over the output.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Reflect)]
+pub struct ConsoleSelection {
+    /// Where the drag started.
+    pub anchor: Option<SelectionPoint>,
+    /// Where the drag is now.
+    pub head: Option<SelectionPoint>,
+}
+impl ConsoleSelection {
+    /// The selected range in reading order. `None` if nothing is selected.
+    pub fn range(&self) -> Option<(SelectionPoint, SelectionPoint)> {
+        let (anchor, head) = (self.anchor?, self.head?);
+        (anchor != head).then(|| (anchor.min(head), anchor.max(head)))
+    }
+
+    pub fn clear(&mut self) {
+        self.anchor = None;
+        self.head = None;
+    }
+
+    /// Moves the selection up past the lines the buffer evicted, given the
+    /// overwritten string returned by [ConsoleBuffer::write]. Clears the
+    /// selection if any of its lines were evicted.
+    pub fn evict(&mut self, overwritten: &str) {
+        // Matches [ConsoleBuffer::as_lines], which skips empty lines.
+        let count = overwritten.split('\n').filter(|l| !l.is_empty()).count();
+        if count == 0 {
+            return;
+        }
+        let shift = |point: Option<SelectionPoint>| {
+            let point = point?;
+            Some(SelectionPoint {
+                line: point.line.checked_sub(count)?,
+                ..point
+            })
+        };
+        match (shift(self.anchor), shift(self.head)) {
+            (Some(anchor), Some(head)) => {
+                self.anchor = Some(anchor);
+                self.head = Some(head);
+            }
+            _ => self.clear(),
+        }
+    }
+
+    /// Collects the selected text from the buffer, with lines joined by '\n'.
+    pub fn text(&self, buffer: &ConsoleBuffer) -> Option<String> {
+        let (start, end) = self.range()?;
+        let lines = buffer
+            .as_lines()
+            .into_iter()
+            .enumerate()
+            .skip(start.line)
+            .take(end.line + 1 - start.line)
+            .map(|(i, line)| {
+                let line = line.into_iter().collect::<String>();
+                let from = if i == start.line { start.index } else { 0 };
+                let to = if i == end.line { end.index } else { line.len() };
+                line.get(from.min(line.len())..to.min(line.len()))
+                    .unwrap_or_default()
+                    .to_owned()
+            })
+            .collect::<Vec<_>>();
+        (!lines.is_empty()).then(|| lines.join("\n"))
+    }
+
+    /// Starts a new selection at the pressed position.
+    pub(crate) fn on_press(
+        trigger: On<Pointer<Press>>,
+        mut q: Query<(
+            &mut ConsoleSelection,
+            &mut ComputedConsoleTextBlock,
+            &ComputedNode,
+            &UiGlobalTransform,
+            &ConsoleBuffer,
+            &ConsoleBufferView,
+        )>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        let (mut selection, mut block, node, transform, buffer, view) =
+            r!(q.get_mut(trigger.entity));
+        let point = hit(
+            &block,
+            node,
+            transform,
+            buffer,
+            view,
+            trigger.pointer_location.position,
+        );
+        selection.anchor = point;
+        selection.head = point;
+        block.trigger_rerender();
+    }
+
+    /// Extends the selection to the dragged position.
+    pub(crate) fn on_drag(
+        trigger: On<Pointer<Drag>>,
+        mut q: Query<(
+            &mut ConsoleSelection,
+            &mut ComputedConsoleTextBlock,
+            &ComputedNode,
+            &UiGlobalTransform,
+            &ConsoleBuffer,
+            &ConsoleBufferView,
+        )>,
+    ) {
+        if trigger.button != PointerButton::Primary {
+            return;
+        }
+        let (mut selection, mut block, node, transform, buffer, view) =
+            r!(q.get_mut(trigger.entity));
+        if selection.anchor.is_none() {
+            return;
+        }
+        let point = rq!(hit(
+            &block,
+            node,
+            transform,
+            buffer,
+            view,
+            trigger.pointer_location.position,
+        ));
+        if selection.head != Some(point) {
+            selection.head = Some(point);
+            block.trigger_rerender();
+        }
+    }
+}
+
+/// Finds the buffer position under the pointer with cosmic-text's hit-testing.
+/// `position` is in logical pixels.
+fn hit(
+    block: &ComputedConsoleTextBlock,
+    node: &ComputedNode,
+    transform: &UiGlobalTransform,
+    buffer: &ConsoleBuffer,
+    view: &ConsoleBufferView,
+    position: Vec2,
+) -> Option<SelectionPoint> {
+    // Physical pixels from the top left of the node.
+    let point = transform
+        .inverse()
+        .transform_point2(position / node.inverse_scale_factor())
+        + node.size() / 2.;
+    // Lines are flipped around their baselines when laid out, so find the
+    // line first and hit-test within it.
+    let cursor = block.buffer().layout_runs().find_map(|run| {
+        let top = node.size.y.round() - run.line_y.round() - (run.line_y - run.line_top);
+        (top..top + run.line_height)
+            .contains(&point.y)
+            .then(|| {
+                let y = run.line_top + (point.y - top);
+                block.buffer().hit(point.x, y)
+            })
+            .flatten()
+    })?;
+    let line = buffer
+        .as_lines()
+        .len()
+        .checked_sub(1 + view.start + cursor.line)?;
+    Some(SelectionPoint {
+        line,
+        index: cursor.index,
+    })
+}
+
+/// A [ConsoleSelection] relative to the view, for the text pipeline.
+#[derive(Debug, Clone, Copy)]
+pub struct SelectionInView {
+    /// The buffer line shown at the bottom of the view.
+    pub bottom_line: usize,
+    pub start: SelectionPoint,
+    pub end: SelectionPoint,
+}
+impl SelectionInView {
+    pub fn new(
+        selection: &ConsoleSelection,
+        buffer: &ConsoleBuffer,
+        view: &ConsoleBufferView,
+    ) -> Option<Self> {
+        let (start, end) = selection.range()?;
+        let bottom_line = buffer.as_lines().len().checked_sub(1 + view.start)?;
+        Some(Self {
+            bottom_line,
+            start,
+            end,
+        })
+    }
+    /// The selected byte range of the given layout line, if any.
+    pub fn span(&self, line_i: usize, line_len: usize) -> Option<(usize, usize)> {
+        let line = self.bottom_line.checked_sub(line_i)?;
+        if line < self.start.line || line > self.end.line {
+            return None;
+        }
+        let from = if line == self.start.line {
+            self.start.index
+        } else {
+            0
+        };
+        let to = if line == self.end.line {
+            self.end.index
+        } else {
+            line_len
+        };
+        Some((from.min(line_len), to.min(line_len)))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_selection_text() {
+        let mut buffer = ConsoleBuffer::new(64);
+        buffer.write("first line\nsecond\n> third").unwrap();
+        let mut selection = ConsoleSelection {
+            anchor: Some(SelectionPoint { line: 1, index: 3 }),
+            head: Some(SelectionPoint { line: 0, index: 6 }),
+        };
+        assert_eq!(selection.text(&buffer).as_deref(), Some("line\nsec"));
+        selection.head = selection.anchor;
+        assert_eq!(selection.text(&buffer), None);
+        selection.head = Some(SelectionPoint { line: 2, index: 99 });
+        assert_eq!(selection.text(&buffer).as_deref(), Some("ond\n> third"));
+    }
+
+    #[test]
+    fn test_selection_evict() {
+        let mut buffer = ConsoleBuffer::new(24);
+        buffer.write("first\nsecond\nthird\n").unwrap();
+        let mut selection = ConsoleSelection {
+            anchor: Some(SelectionPoint { line: 1, index: 0 }),
+            head: Some(SelectionPoint { line: 2, index: 3 }),
+        };
+        assert_eq!(selection.text(&buffer).as_deref(), Some("second\nthi"));
+
+        let overwritten = buffer.write("fourth\n").unwrap();
+        assert_eq!(overwritten, "first\n");
+        selection.evict(&overwritten);
+        assert_eq!(selection.text(&buffer).as_deref(), Some("second\nthi"));
+
+        let overwritten = buffer.write("fifth\n").unwrap();
+        selection.evict(&overwritten);
+        assert_eq!(selection, ConsoleSelection::default());
+    }
+}
@@ -15,18 +15,41 @@ pub struct ConsoleTextLayout {
     pub linebreak: LineBreak,
 }
 
+/// The console's caret, a child node placed at the input cursor by
+/// [update_console_caret]. Hidden while the console is not focused.
+#[derive(Component, Default, Reflect, Debug, Clone, Copy)]
+#[require(Node, BackgroundColor, Pickable = Pickable::IGNORE)]
+pub struct ConsoleCaret;
+
+/// Highlights a line of the console's [ConsoleSelection]. These are spawned
+/// as children of the console by [update_console_selection] as needed.
+#[derive(Component, Default, Reflect, Debug, Clone, Copy)]
+#[require(Node, BackgroundColor, Pickable = Pickable::IGNORE)]
+pub struct ConsoleSelectionHighlight;
+
 /// Ideally, this would just be a [bevy::text::ComputedTextBlock], but it's fields are currently private.
 #[derive(Component, Debug, Clone)]
 pub struct ComputedConsoleTextBlock {
     pub(crate) buffer: CosmicBuffer,
     pub(crate) needs_rerender: bool,
     pub(crate) entities: SmallVec<[TextEntity; 1]>,
+    /// The input cursor's line box in physical pixels, measured from the top
+    /// left of the node. `None` if the cursor is out of view.
+    pub(crate) caret: Option<Rect>,
+    /// The selection's highlighted line boxes, in the same space as `caret`.
+    pub(crate) selection: Vec<Rect>,
 }
 
 impl ComputedConsoleTextBlock {
     pub fn buffer(&self) -> &CosmicBuffer {
         &self.buffer
     }
+    pub fn caret(&self) -> Option<Rect> {
+        self.caret
+    }
+    pub fn selection(&self) -> &[Rect] {
+        &self.selection
+    }
     pub fn trigger_rerender(&mut self) {
         self.needs_rerender = true;
     }
@@ -37,6 +60,8 @@ impl Default for ComputedConsoleTextBlock {
             buffer: Default::default(),
             needs_rerender: true,
             entities: Default::default(),
+            caret: None,
+            selection: Vec::new(),
         }
     }
 }
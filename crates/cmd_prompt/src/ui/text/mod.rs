@@ -24,6 +24,11 @@ pub fn plugin(app: &mut App) {
             .ambiguous_with(bevy::sprite::calculate_bounds_text2d),),
     );
 
+    app.add_systems(
+        PostUpdate,
+        (update_console_caret, update_console_selection).after(update_console_text_layout),
+    );
+
     let Some(render_app) = app.get_sub_app_mut(RenderApp) else {
         return;
     };
@@ -3,6 +3,7 @@ use crate::{prelude::*, ui::calc_line_height};
 use std::sync::Arc;
 
 use bevy::{
+    input_focus::InputFocus,
     math::Affine2,
     platform::collections::HashMap,
     render::{Extract, sync_world::TemporaryRenderEntity},
@@ -16,7 +17,9 @@ use bevy::{
         stack_z_offsets,
     },
 };
-use cosmic_text::{Attrs, AttrsList, BufferLine, Family, LineIter, Metrics, Shaping, Wrap};
+use cosmic_text::{
+    Attrs, AttrsList, BufferLine, Cursor, Family, LayoutRun, LineIter, Metrics, Shaping, Wrap,
+};
 
 #[derive(Debug)]
 pub struct GlyphSectionInfo {
@@ -225,7 +228,11 @@ impl ConsoleTextPipeline {
         textures: &mut Assets<Image>,
         swash_cache: &mut SwashCache,
         node: &ComputedNode,
+        caret: Option<usize>,
+        selection: Option<SelectionInView>,
     ) -> Result<(), TextError> {
+        computed.caret = None;
+        computed.selection.clear();
         layout_info.glyphs.clear();
         layout_info.run_geometry.clear();
         layout_info.size = Vec2::default();
@@ -271,6 +278,26 @@ impl ConsoleTextPipeline {
             if box_size.y >= view.range as f32 * run.line_height {
                 break;
             }
+            // The input is the bottom line, which is the first in the buffer.
+            if let Some(index) = caret
+                && run.line_i == 0
+                && let Some((x, exact)) = caret_x(&run, index)
+                && (exact || computed.caret.is_none())
+            {
+                // Like the glyphs, the line is flipped around its baseline.
+                let top = node.size.y.round() - run.line_y.round() - (run.line_y - run.line_top);
+                computed.caret = Some(Rect::new(x, top, x, top + run.line_height));
+            }
+            if let Some(selection) = selection
+                && let Some((from, to)) = selection.span(run.line_i, run.text.len())
+                && let Some((x, w)) =
+                    run.highlight(Cursor::new(run.line_i, from), Cursor::new(run.line_i, to))
+            {
+                let top = node.size.y.round() - run.line_y.round() - (run.line_y - run.line_top);
+                computed
+                    .selection
+                    .push(Rect::new(x, top, x + w, top + run.line_height));
+            }
             let mut current_section: Option<usize> = None;
             let mut start = 0.;
             let mut end = 0.;
@@ -399,6 +426,23 @@ impl ConsoleTextPipeline {
     }
 }
 
+/// Finds the x position of the byte `index` in the run. The flag is false if
+/// the index is just past the run's end, since a wrapped line continues in the
+/// next run.
+fn caret_x(run: &LayoutRun, index: usize) -> Option<(f32, bool)> {
+    if let Some(glyph) = run
+        .glyphs
+        .iter()
+        .find(|glyph| (glyph.start..glyph.end).contains(&index))
+    {
+        return Some((glyph.x, true));
+    }
+    match run.glyphs.last() {
+        Some(glyph) => (glyph.end == index).then_some((glyph.x + glyph.w, false)),
+        None => Some((0., false)),
+    }
+}
+
 /// Translates [`TextFont`] to [`Attrs`].
 fn get_attrs<'a>(
     span_index: usize,
@@ -554,6 +598,10 @@ pub fn update_console_text_layout(
         &mut TextLayoutInfo,
         &mut ConsoleBufferFlags,
         &mut ComputedConsoleTextBlock,
+        &ConsoleInputText,
+        &ConsolePrompt,
+        &ConsoleSelection,
+        &ConsoleBuffer,
     )>,
     text_font: Query<&TextFont>,
     mut font_system: ResMut<CosmicFontSystem>,
@@ -562,8 +610,27 @@ pub fn update_console_text_layout(
     mut textures: ResMut<Assets<Image>>,
     mut swash_cache: ResMut<SwashCache>,
 ) {
-    for (node, layout, view, mut layout_info, mut flags, mut computed) in console_q {
+    for (
+        node,
+        layout,
+        view,
+        mut layout_info,
+        mut flags,
+        mut computed,
+        input,
+        prompt,
+        selection,
+        buffer,
+    ) in console_q
+    {
         if node.is_changed() || flags.needs_recompute {
+            // The cursor's offset in the bottom line, unless it's scrolled out
+            // of view or on an earlier line of a multiline input.
+            let caret =
+                (view.start == 0 && !input.text()[input.cursor()..].contains('\n')).then(|| {
+                    let head = format!("{}{}", prompt.0, &input.text()[..input.cursor()]);
+                    head.len() - head.rfind('\n').map_or(0, |i| i + 1)
+                });
             let scale_factor = node.inverse_scale_factor().recip().into();
             let physical_node_size = if layout.linebreak == LineBreak::NoWrap {
                 // With `NoWrap` set, no constraints are placed on the width of the text.
@@ -585,6 +652,8 @@ pub fn update_console_text_layout(
                 &mut textures,
                 &mut swash_cache,
                 &node,
+                caret,
+                SelectionInView::new(selection, buffer, view),
             ) {
                 Ok(()) => {
                     layout_info.scale_factor = scale_factor as f32;
@@ -609,6 +678,93 @@ pub fn update_console_text_layout(
     }
 }
 
+/// The caret's width in logical pixels.
+const CARET_WIDTH: f32 = 2.;
+
+/// Moves each console's [ConsoleCaret] to the position found by
+/// [ConsoleTextPipeline::update_layout_info].
+pub fn update_console_caret(
+    console_q: Query<(
+        Entity,
+        &ComputedConsoleTextBlock,
+        &ComputedNode,
+        &ConsoleUiSettings,
+        &Children,
+    )>,
+    mut caret_q: Query<(&mut Node, &mut BackgroundColor), With<ConsoleCaret>>,
+    focus: Res<InputFocus>,
+) {
+    for (console_id, computed, node, settings, children) in console_q {
+        let caret = computed.caret().filter(|_| focus.0 == Some(console_id));
+        let scale = node.inverse_scale_factor();
+        let new_node = match caret {
+            Some(rect) => Node {
+                position_type: PositionType::Absolute,
+                left: Val::Px(rect.min.x * scale),
+                top: Val::Px(rect.min.y * scale),
+                width: Val::Px(CARET_WIDTH),
+                height: Val::Px(rect.height() * scale),
+                ..default()
+            },
+            None => Node {
+                position_type: PositionType::Absolute,
+                display: Display::None,
+                ..default()
+            },
+        };
+        let mut carets = caret_q.iter_many_mut(children);
+        while let Some((mut caret_node, mut color)) = carets.fetch_next() {
+            caret_node.set_if_neq(new_node.clone());
+            color.set_if_neq(BackgroundColor(settings.font_color));
+        }
+    }
+}
+
+/// Highlights each console's [ConsoleSelection] with
+/// [ConsoleSelectionHighlight] nodes, spawning more as needed and hiding the
+/// rest.
+pub fn update_console_selection(
+    console_q: Query<(
+        Entity,
+        &ComputedConsoleTextBlock,
+        &ComputedNode,
+        &ConsoleUiSettings,
+        Option<&Children>,
+    )>,
+    mut highlight_q: Query<(&mut Node, &mut BackgroundColor), With<ConsoleSelectionHighlight>>,
+    mut commands: Commands,
+) {
+    for (console_id, computed, node, settings, children) in console_q {
+        let scale = node.inverse_scale_factor();
+        let color = BackgroundColor(settings.font_color.with_alpha(0.3));
+        let mut rects = computed.selection().iter().map(|rect| Node {
+            position_type: PositionType::Absolute,
+            left: Val::Px(rect.min.x * scale),
+            top: Val::Px(rect.min.y * scale),
+            width: Val::Px(rect.width() * scale),
+            height: Val::Px(rect.height() * scale),
+            ..default()
+        });
+        if let Some(children) = children {
+            let mut highlights = highlight_q.iter_many_mut(children);
+            while let Some((mut highlight, mut highlight_color)) = highlights.fetch_next() {
+                let new_node = rects.next().unwrap_or_else(|| Node {
+                    position_type: PositionType::Absolute,
+                    display: Display::None,
+                    ..default()
+                });
+                highlight.set_if_neq(new_node);
+                highlight_color.set_if_neq(color);
+            }
+        }
+        for new_node in rects {
+            commands
+                .entity(console_id)
+                .with_child((ConsoleSelectionHighlight, new_node, color));
+        }
+    }
+}
+
 // If we can use a ComputedTextBlock above, then we won't need this function.
 /// Extracts the console glyphs for rendering
 pub fn extract_console_text_sections(
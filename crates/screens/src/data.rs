@@ -1,9 +1,12 @@
 use crate::prelude::*;
-use bevy::ecs::{
-    schedule::ScheduleLabel,
-    system::{ReadOnlySystemParam, SystemParam},
+use bevy::{
+    ecs::{
+        schedule::ScheduleLabel,
+        system::{ReadOnlySystemParam, SystemChangeTick, SystemId, SystemParam},
+    },
+    reflect::TypeRegistry,
 };
-use std::{any::TypeId, marker::PhantomData};
+use std::{any::TypeId, marker::PhantomData, sync::Arc};
 
 mod general_api {
     use thiserror::Error;
@@ -17,24 +20,196 @@ mod general_api {
         NoSuchScreen(String),
         #[error("Could not find screen with ID {0:?}!")]
         NoSuchScreenId(ScreenId),
+        #[error("Invalid parameters for screen {0}: {1}")]
+        InvalidParams(String, String),
+        #[error("Resource {0} is scoped to screen {1}, which is {2:?}")]
+        ScopedResourceUnavailable(String, String, ScreenState),
+        #[error("Switching from {0} to {1} is not allowed by the ScreenFlow")]
+        IllegalSwitch(String, String),
     }
 
     /// Call this when you want to switch screens. This will trigger a
-    /// [SwitchToScreenMsg] with the screen's [ComponentId].
-    #[derive(Event, Debug, PartialEq, Eq, Clone, Deref, Default)]
-    pub struct SwitchToScreen<S: Screen>(PhantomData<S>);
+    /// [SwitchToScreenMsg] with the screen's [ScreenId] and [Screen::Params].
+    #[derive(Event, Debug)]
+    pub struct SwitchToScreen<S: Screen> {
+        /// Parameters for the screen. Readable through [ScreenParams].
+        pub params: S::Params,
+        /// Overrides the screen's default [ScreenTransition].
+        pub transition: Option<Arc<dyn ScreenTransition>>,
+        /// Skips the exit guards of the screens being left. See
+        /// [ScreenScopeBuilder::with_exit_guard].
+        pub force: bool,
+        _ghost: PhantomData<S>,
+    }
+    impl<S: Screen> SwitchToScreen<S> {
+        /// Switches to the screen with the given params.
+        pub fn new(params: S::Params) -> Self {
+            Self {
+                params,
+                transition: None,
+                force: false,
+                _ghost: PhantomData,
+            }
+        }
+        #[allow(missing_docs)]
+        pub fn with_params(mut self, params: S::Params) -> Self {
+            self.params = params;
+            self
+        }
+        /// Plays this transition instead of the screen's default.
+        pub fn with_transition(mut self, transition: impl ScreenTransition) -> Self {
+            self.transition = Some(Arc::new(transition));
+            self
+        }
+        /// Switches even if an exit guard would deny or defer it.
+        pub fn forced(mut self) -> Self {
+            self.force = true;
+            self
+        }
+    }
+    impl<S: Screen> Default for SwitchToScreen<S> {
+        fn default() -> Self {
+            Self::new(S::Params::default())
+        }
+    }
+    impl<S: Screen> Clone for SwitchToScreen<S> {
+        fn clone(&self) -> Self {
+            Self {
+                params: self.params.clone(),
+                transition: self.transition.clone(),
+                force: self.force,
+                _ghost: PhantomData,
+            }
+        }
+    }
 
     /// See [SwitchToScreen]
     pub fn switch_to_screen<S: Screen>() -> SwitchToScreen<S> {
         SwitchToScreen::<S>::default()
     }
 
-    /// Switches to the given screen by its [ComponentId]. When possible, prefer
+    /// Switches to the given screen by its [ScreenId]. When possible, prefer
     /// to use [SwitchToScreen] to ensure type safety. This is a [Message] so we
     /// can buffer any [SwitchToScreenMsg]s to avoid conflicts. Only the last
     /// valid [SwitchToScreenMsg] will be read.
-    #[derive(Message, Debug, PartialEq, Eq, Clone, Deref)]
-    pub struct SwitchToScreenMsg(pub ScreenId);
+    #[derive(Message, Debug, Deref)]
+    pub struct SwitchToScreenMsg {
+        #[deref]
+        #[allow(missing_docs)]
+        pub id: ScreenId,
+        /// Parameters for the screen. These must be convertible to the
+        /// screen's [Screen::Params] with [FromReflect], otherwise the message
+        /// is ignored. If `None`, the screen's params are reset to their default.
+        /// See [ScreenInfo::parse_params] to build these from a string.
+        pub params: Option<Box<dyn PartialReflect>>,
+        /// Overrides the screen's default [ScreenTransition].
+        pub transition: Option<Arc<dyn ScreenTransition>>,
+        /// Skips the exit guards of the screens being left. See
+        /// [ScreenScopeBuilder::with_exit_guard].
+        pub force: bool,
+    }
+    impl SwitchToScreenMsg {
+        /// Switches to the screen with default params.
+        pub fn new(id: ScreenId) -> Self {
+            Self {
+                id,
+                params: None,
+                transition: None,
+                force: false,
+            }
+        }
+        #[allow(missing_docs)]
+        pub fn with_params(mut self, params: Box<dyn PartialReflect>) -> Self {
+            self.params = Some(params);
+            self
+        }
+        /// Plays this transition instead of the screen's default.
+        pub fn with_transition(mut self, transition: Arc<dyn ScreenTransition>) -> Self {
+            self.transition = Some(transition);
+            self
+        }
+        /// Switches even if an exit guard would deny or defer it.
+        pub fn forced(mut self) -> Self {
+            self.force = true;
+            self
+        }
+    }
+    impl Clone for SwitchToScreenMsg {
+        fn clone(&self) -> Self {
+            Self {
+                id: self.id,
+                params: self.params.as_ref().map(|p| p.to_dynamic()),
+                transition: self.transition.clone(),
+                force: self.force,
+            }
+        }
+    }
+
+    /// Pushes a screen on top of the [ScreenStack]. Unlike [SwitchToScreen],
+    /// this does not unload any other screens. The screen beneath it will be
+    /// covered, see [CoveredPolicy].
+    #[derive(Event, Debug, PartialEq, Eq, Clone, Deref, Default)]
+    pub struct PushScreen<S: Screen>(PhantomData<S>);
+
+    /// See [PushScreen]
+    pub fn push_screen<S: Screen>() -> PushScreen<S> {
+        PushScreen::<S>::default()
+    }
+
+    /// Unloads the top-most screen on the [ScreenStack] and uncovers the
+    /// screen beneath it. Has no effect if only one screen is on the stack.
+    #[derive(Event, Debug, PartialEq, Eq, Clone, Copy, Default)]
+    pub struct PopScreen;
+
+    /// Switches back to the previous screen in the [ScreenHistory], with the
+    /// params it had when it was last switched to. Has no effect if there is
+    /// no previous screen.
+    ///
+    /// The history is only rewound once the switch happens, so a switch which
+    /// is denied by an exit guard or rejected by the [ScreenFlow] keeps it.
+    #[derive(Event, Debug, PartialEq, Eq, Clone, Copy, Default)]
+    pub struct GoBack {
+        /// Use the screen's default params instead of its earlier ones.
+        pub reset_params: bool,
+        /// Skips the exit guards of the screens being left, e.g. once the
+        /// player has confirmed a [ScreenSwitchDenied]. See
+        /// [SwitchToScreenMsg::force].
+        pub force: bool,
+    }
+    impl GoBack {
+        /// Goes back even if an exit guard would deny or defer it.
+        pub fn forced(mut self) -> Self {
+            self.force = true;
+            self
+        }
+    }
+
+    /// See [GoBack]
+    pub fn go_back() -> GoBack {
+        GoBack::default()
+    }
+
+    /// Pushes or pops screens on the [ScreenStack]. When possible, prefer to
+    /// use [PushScreen] and [PopScreen]. Messages are read in order.
+    #[derive(Message, Debug, PartialEq, Eq, Clone, Copy)]
+    pub enum ScreenStackMsg {
+        /// See [PushScreen]
+        Push(ScreenId),
+        /// See [PopScreen]
+        Pop,
+    }
+
+    /// Signals that a screen switch was cancelled because the exit guard of
+    /// the screen `from` returned [ExitDecision::Deny]. Use this to ask the
+    /// player for confirmation, then switch again with
+    /// [SwitchToScreen::forced].
+    #[derive(Event, Debug, PartialEq, Eq, Clone)]
+    pub struct ScreenSwitchDenied {
+        #[allow(missing_docs)]
+        pub from: ScreenId,
+        #[allow(missing_docs)]
+        pub to: ScreenId,
+    }
 
     /// Signals that the current screen has changed.
     #[derive(Event, Debug, PartialEq, Eq, Clone)]
@@ -55,6 +230,81 @@ mod general_api {
         FinishLoading::<S>::default()
     }
 
+    /// Will cause the given screen to fail loading with the given reason. The
+    /// screen moves to [ScreenState::Failed], and the [FallbackScreen] is
+    /// switched to. Has no effect if the screen is not currently loading.
+    #[derive(Event, Debug, PartialEq, Eq, Clone)]
+    pub struct FailLoading<S: Screen> {
+        #[allow(missing_docs)]
+        pub reason: String,
+        _ghost: PhantomData<S>,
+    }
+    impl<S: Screen> FailLoading<S> {
+        #[allow(missing_docs)]
+        pub fn new(reason: impl ToString) -> Self {
+            Self {
+                reason: reason.to_string(),
+                _ghost: PhantomData,
+            }
+        }
+    }
+
+    /// See [FailLoading]
+    pub fn fail_loading<S: Screen>(reason: impl ToString) -> FailLoading<S> {
+        FailLoading::<S>::new(reason)
+    }
+
+    /// Loads the given screen in the background, without switching to it. The
+    /// screen runs its [ScreenSchedule::Loading] schedule while the current
+    /// screen keeps running, but does not run its [ScreenSchedule::Update]
+    /// schedules or [ScreenSchedule::OnReady] until it is switched to. If it
+    /// has finished loading by then, it becomes active immediately.
+    ///
+    /// Entities spawned while preloading are visible, so prefer spawning in
+    /// [ScreenSchedule::OnReady]. Switching to another screen cancels the
+    /// preload, and so does [CancelPreload]. If loading fails, the screen is
+    /// unloaded instead of switching to the [FallbackScreen].
+    /// Has no effect if the screen is not Unloaded.
+    #[derive(Event, Debug, PartialEq, Eq, Clone, Deref, Default)]
+    pub struct PreloadScreen<S: Screen>(PhantomData<S>);
+
+    /// See [PreloadScreen]
+    pub fn preload_screen<S: Screen>() -> PreloadScreen<S> {
+        PreloadScreen::<S>::default()
+    }
+
+    /// Pauses the given screen. Its [ScreenSchedule::Update] and
+    /// [ScreenSchedule::FixedUpdate] schedules stop, and its
+    /// [ScreenSchedule::Paused] schedule runs instead. Its entities stay alive
+    /// and its [ScreenClock] stops. Has no effect if the screen is not Ready.
+    #[derive(Event, Debug, PartialEq, Eq, Clone, Deref, Default)]
+    pub struct PauseScreen<S: Screen>(PhantomData<S>);
+
+    /// See [PauseScreen]
+    pub fn pause_screen<S: Screen>() -> PauseScreen<S> {
+        PauseScreen::<S>::default()
+    }
+
+    /// Resumes a screen paused with [PauseScreen]. Has no effect if the
+    /// screen is not Paused.
+    #[derive(Event, Debug, PartialEq, Eq, Clone, Deref, Default)]
+    pub struct ResumeScreen<S: Screen>(PhantomData<S>);
+
+    /// See [ResumeScreen]
+    pub fn resume_screen<S: Screen>() -> ResumeScreen<S> {
+        ResumeScreen::<S>::default()
+    }
+
+    /// Cancels a [PreloadScreen], unloading the screen. Has no effect if the
+    /// screen is not preloading.
+    #[derive(Event, Debug, PartialEq, Eq, Clone, Deref, Default)]
+    pub struct CancelPreload<S: Screen>(PhantomData<S>);
+
+    /// See [CancelPreload]
+    pub fn cancel_preload<S: Screen>() -> CancelPreload<S> {
+        CancelPreload::<S>::default()
+    }
+
     /// Will cause the given screen to finish unloading. Has no effect if the
     /// screen is not currently unloading.
     #[derive(Event, Debug, PartialEq, Eq, Clone, Deref, Default)]
@@ -65,9 +315,10 @@ mod general_api {
         FinishUnloading::<S>::default()
     }
 
-    /// Scopes an entity to the current screen. The entity will be cleaned up when
-    /// the [Screen] state changes. By default, all entities _except_ top-level
-    /// [Observer] and [Window] components are screen-scoped.
+    /// Scopes an entity to its owning screen. The entity will be cleaned up when
+    /// that [Screen] unloads. By default, all entities owned by a screen
+    /// _except_ top-level [Observer] and [Window] components are screen-scoped.
+    /// See [ScreenOwner].
     ///
     /// Note: This is effectively used to skip the propagation of the
     /// [Persistent] component. Since screen scoping is the default behavior, it
@@ -84,6 +335,46 @@ mod general_api {
     #[derive(Component, Debug, Reflect, Clone, Copy, Default, PartialEq)]
     pub struct Persistent;
 
+    /// Marks an observer scoped to its owning screen, see
+    /// [ScreenScopeBuilder::add_observer]. Unlike other top-level observers,
+    /// these are despawned when the screen is cleaned up.
+    #[derive(Component, Debug, Reflect, Clone, Copy, Default, PartialEq)]
+    pub struct ScreenObserver;
+
+    /// Records which screen owns an entity. Only the owning screen will clean
+    /// up the entity when it unloads.
+    ///
+    /// This is inserted automatically on every entity spawned while one of
+    /// the screen's schedules is running, e.g. [ScreenSchedule::Loading],
+    /// [ScreenSchedule::OnLoad] or [ScreenSchedule::Update]. Entities spawned
+    /// outside of a screen's schedules are not owned by any screen and will
+    /// not be cleaned up. You can insert this component yourself to scope such
+    /// an entity to a screen.
+    #[derive(Component, Debug, Reflect, Clone, Copy, PartialEq, Eq, Deref)]
+    pub struct ScreenOwner(pub ScreenId);
+
+    /// The root of a scene loaded with [ScreenScopeBuilder::with_scene]. It is
+    /// spawned when the screen starts loading, and the scene is spawned
+    /// beneath it once the screen is Ready. Entities in the scene are owned by
+    /// the same screen as the root.
+    #[derive(Component, Debug, Clone, PartialEq, Eq, Deref)]
+    pub struct ScreenSceneRoot(pub Handle<DynamicScene>);
+
+    /// The screen switched to when another screen fails to load, see
+    /// [FailLoading]. Typically this will be a main menu or an error screen.
+    #[derive(Resource, Default, Debug, Deref)]
+    pub struct FallbackScreen(Option<String>);
+    impl FallbackScreen {
+        #[allow(missing_docs)]
+        pub fn new<S: Screen>() -> Self {
+            Self(Some(S::name()))
+        }
+        #[allow(missing_docs)]
+        pub fn from_name(name: String) -> Self {
+            Self(Some(name))
+        }
+    }
+
     /// The first screen. Typically this will be a splash screen, a loading
     /// screen, or a main menu.
     #[derive(Resource, Default, Debug, Deref)]
@@ -106,23 +397,65 @@ mod screens {
 
     use super::*;
 
-    /// Generates [`ScreenId`]s.
+    /// The screen's ID, unique within an [App]. Typed screens keep their ID
+    /// when they are re-registered. Get it with [ScreenIdFor] or
+    /// [ScreenRegistry::get].
+    #[derive(Clone, Debug, PartialEq, Eq, Hash, Deref, Copy, Reflect)]
+    pub struct ScreenId(pub(crate) usize);
+
+    /// Generates [`ScreenId`]s. IDs are dense, so they can index [ScreenData].
     #[derive(Resource, Debug, Default)]
     pub(crate) struct ScreenIds {
-        next: bevy::platform::sync::atomic::AtomicUsize,
+        next: usize,
+        typed: TypeIdMap<ScreenId>,
     }
-
     impl ScreenIds {
-        pub fn next(&self) -> ScreenId {
-            ScreenId(
-                self.next
-                    .fetch_add(1, bevy::platform::sync::atomic::Ordering::Relaxed),
-            )
+        /// The ID of the screen with the given type. The same type always
+        /// gets the same ID, even if it is not registered yet.
+        pub fn of(&mut self, type_id: TypeId) -> ScreenId {
+            if let Some(id) = self.typed.get(&type_id) {
+                return *id;
+            }
+            let id = self.next();
+            self.typed.insert(type_id, id);
+            id
+        }
+        /// A new, unique ID.
+        pub fn next(&mut self) -> ScreenId {
+            self.next += 1;
+            ScreenId(self.next - 1)
+        }
+        /// Resolves a [ScreenKey] to the screen's ID.
+        pub fn resolve(&mut self, key: ScreenKey) -> ScreenId {
+            match key {
+                ScreenKey::Type(type_id) => self.of(type_id),
+                ScreenKey::Id(id) => id,
+            }
+        }
+    }
+
+    /// Names a screen without access to the [World]. Typed screens are named
+    /// by their type, so their schedule labels can be built before the screen
+    /// is registered, see [on_screen_ready]. Dynamic screens are named by
+    /// their [ScreenId].
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+    pub enum ScreenKey {
+        #[allow(missing_docs)]
+        Type(TypeId),
+        #[allow(missing_docs)]
+        Id(ScreenId),
+    }
+    impl ScreenKey {
+        #[allow(missing_docs)]
+        pub fn of<S: Screen>() -> Self {
+            Self::Type(TypeId::of::<S>())
+        }
+    }
+    impl From<ScreenId> for ScreenKey {
+        fn from(id: ScreenId) -> Self {
+            Self::Id(id)
         }
     }
-    /// The screen's ID.
-    #[derive(Clone, Debug, PartialEq, Eq, Deref, Copy, Reflect)]
-    pub struct ScreenId(pub(crate) usize);
 
     /// The screen registry holds a map between the screen's type id and it's [ScreenId].
     #[derive(Resource, Debug, Deref, DerefMut, Default)]
@@ -164,6 +497,16 @@ mod screens {
         pub fn iter_some_mut(&mut self) -> impl Iterator<Item = &mut ScreenInfo> {
             self.0.iter_mut().filter_map(|v| v.as_mut())
         }
+        pub(crate) fn insert(&mut self, info: ScreenInfo) {
+            let id = *info.screen_id();
+            if self.0.len() <= id {
+                self.0.resize_with(id + 1, || None);
+            }
+            self.0[id] = Some(info);
+        }
+        pub(crate) fn remove(&mut self, id: ScreenId) -> Option<ScreenInfo> {
+            self.0.get_mut(*id).and_then(Option::take)
+        }
     }
 
     /// The current screen's ID.
@@ -177,14 +520,338 @@ mod screens {
         }
     }
 
+    /// The stack of loaded screens. The bottom of the stack is the screen
+    /// which was last switched to with [SwitchToScreen], and the top is the
+    /// [CurrentScreen]. Screens are added with [PushScreen] and removed with
+    /// [PopScreen]. Switching screens clears the stack.
+    #[derive(Resource, Debug, Deref, Default)]
+    pub struct ScreenStack(pub(crate) Vec<ScreenId>);
+    impl ScreenStack {
+        /// The top-most screen.
+        pub fn top(&self) -> Option<ScreenId> {
+            self.0.last().copied()
+        }
+        /// Is the screen on the stack, but not on top?
+        pub fn is_covered(&self, id: ScreenId) -> bool {
+            self.0.iter().rev().skip(1).contains(&id)
+        }
+    }
+
+    /// A screen which was switched to, see [ScreenHistory].
+    #[derive(Debug)]
+    pub struct HistoryEntry {
+        #[allow(missing_docs)]
+        pub id: ScreenId,
+        /// The screen's [Screen::Params] when it was switched to.
+        pub params: Box<dyn PartialReflect>,
+    }
+
+    /// The screens which were switched to, oldest first. The last entry is
+    /// usually the [CurrentScreen]. Use [GoBack] to return to the previous
+    /// screen.
+    ///
+    /// Screens pushed with [PushScreen] are not recorded, and neither are
+    /// screens with [ScreenScopeBuilder::with_skip_history]. Once the history
+    /// is full, the oldest entry is dropped.
+    #[derive(Resource, Debug)]
+    pub struct ScreenHistory {
+        entries: std::collections::VecDeque<HistoryEntry>,
+        capacity: usize,
+        /// The entry which a [GoBack] is switching to, if any.
+        pub(crate) going_back: Option<usize>,
+    }
+    impl Default for ScreenHistory {
+        fn default() -> Self {
+            Self::new(32)
+        }
+    }
+    impl ScreenHistory {
+        /// Creates an empty history which holds at most `capacity` entries.
+        pub fn new(capacity: usize) -> Self {
+            Self {
+                entries: std::collections::VecDeque::with_capacity(capacity),
+                capacity,
+                going_back: None,
+            }
+        }
+        #[allow(missing_docs)]
+        pub fn capacity(&self) -> usize {
+            self.capacity
+        }
+        /// The most recent entry.
+        pub fn last(&self) -> Option<&HistoryEntry> {
+            self.entries.back()
+        }
+        /// Iterates over the entries, oldest first.
+        pub fn iter(&self) -> impl DoubleEndedIterator<Item = &HistoryEntry> {
+            self.entries.iter()
+        }
+        #[allow(missing_docs)]
+        pub fn len(&self) -> usize {
+            self.entries.len()
+        }
+        #[allow(missing_docs)]
+        pub fn is_empty(&self) -> bool {
+            self.entries.is_empty()
+        }
+        #[allow(missing_docs)]
+        pub fn clear(&mut self) {
+            self.entries.clear();
+            self.going_back = None;
+        }
+        pub(crate) fn push(&mut self, entry: HistoryEntry) {
+            if self.capacity == 0 {
+                return;
+            }
+            if self.entries.len() == self.capacity {
+                self.entries.pop_front();
+            }
+            self.entries.push_back(entry);
+        }
+        pub(crate) fn last_mut(&mut self) -> Option<&mut HistoryEntry> {
+            self.entries.back_mut()
+        }
+        pub(crate) fn get(&self, index: usize) -> Option<&HistoryEntry> {
+            self.entries.get(index)
+        }
+        /// Drops every entry after `index`.
+        pub(crate) fn truncate(&mut self, index: usize) {
+            self.entries.truncate(index + 1);
+        }
+        pub(crate) fn remove(&mut self, id: ScreenId) {
+            self.entries.retain(|entry| entry.id != id);
+            self.going_back = None;
+        }
+    }
+
+    fn default_params<P: Reflect + Default>() -> Box<dyn Reflect> {
+        Box::new(P::default())
+    }
+
+    fn params_from_reflect<P: FromReflect>(value: &dyn PartialReflect) -> Option<Box<dyn Reflect>> {
+        P::from_reflect(value).map(|p| Box::new(p) as Box<dyn Reflect>)
+    }
+
+    /// The [ScreenTransition] which is currently playing, if any.
+    #[derive(Resource, Debug, Default)]
+    pub struct ActiveTransition {
+        pub(crate) current: Option<TransitionInfo>,
+        /// Interrupted transitions which still need to finish.
+        pub(crate) interrupted: Vec<Arc<dyn ScreenTransition>>,
+    }
+    impl ActiveTransition {
+        /// Is a transition playing?
+        pub fn is_active(&self) -> bool {
+            self.current.is_some()
+        }
+        /// The current phase of the playing transition.
+        pub fn phase(&self) -> Option<TransitionPhase> {
+            self.current.as_ref().map(|info| info.phase)
+        }
+        /// Plays the transition, interrupting the current one. `from` are the
+        /// screens on the [ScreenStack] being left. If there are no outgoing
+        /// screens the Out phase is skipped.
+        pub(crate) fn play(
+            &mut self,
+            transition: Option<Arc<dyn ScreenTransition>>,
+            from: Vec<ScreenId>,
+            to: ScreenId,
+            has_outgoing: bool,
+        ) {
+            if let Some(current) = self.current.take()
+                && current.started
+            {
+                self.interrupted.push(current.transition);
+            }
+            self.current = transition.map(|transition| TransitionInfo {
+                transition,
+                from,
+                to,
+                phase: if has_outgoing {
+                    TransitionPhase::Out
+                } else {
+                    TransitionPhase::In
+                },
+                elapsed: std::time::Duration::ZERO,
+                started: false,
+            });
+        }
+        /// Should the screen wait before cleaning up? Only the screens being
+        /// faded out wait, until the Out phase is over.
+        pub(crate) fn holds_cleanup(&self, id: ScreenId) -> bool {
+            self.current.as_ref().is_some_and(|info| {
+                matches!(info.phase, TransitionPhase::Out) && info.from.contains(&id)
+            })
+        }
+    }
+
+    /// How many of a screen's tracked assets have loaded, including their
+    /// dependencies. Read it with [ScreenInfo::load_progress], e.g. to draw a
+    /// loading bar. See [ScreenScopeBuilder::load_assets] and
+    /// [ScreenInfoMut::track_assets].
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Reflect)]
+    pub struct ScreenLoadProgress {
+        #[allow(missing_docs)]
+        pub done: usize,
+        #[allow(missing_docs)]
+        pub total: usize,
+    }
+    impl ScreenLoadProgress {
+        /// Progress from 0 to 1. Is 1 if there is nothing to load.
+        pub fn fraction(&self) -> f32 {
+            if self.total == 0 {
+                1.
+            } else {
+                self.done as f32 / self.total as f32
+            }
+        }
+        /// Has every tracked asset loaded?
+        pub fn is_done(&self) -> bool {
+            self.done >= self.total
+        }
+    }
+
+    /// A resource which only exists while its screen is loaded. See
+    /// [ScreenScopeBuilder::init_resource].
+    #[derive(Clone)]
+    pub(crate) struct ScopedResource {
+        pub name: &'static str,
+        pub insert: Arc<dyn Fn(&mut World) + Send + Sync>,
+        pub remove: fn(&mut World),
+    }
+    impl std::fmt::Debug for ScopedResource {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_tuple("ScopedResource").field(&self.name).finish()
+        }
+    }
+
+    /// An observer which only exists while its screen is loaded. See
+    /// [ScreenScopeBuilder::add_observer].
+    #[derive(Clone)]
+    pub(crate) struct ScopedObserver {
+        pub spawn: Arc<dyn Fn(&mut World) -> Entity + Send + Sync>,
+    }
+    impl std::fmt::Debug for ScopedObserver {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("ScopedObserver").finish_non_exhaustive()
+        }
+    }
+
+    /// A list of assets which must load before a screen is Ready.
+    #[derive(Debug)]
+    pub(crate) struct AssetTracker {
+        pub handles: Vec<UntypedHandle>,
+        /// Runs once every tracked asset has loaded, e.g. to insert an asset
+        /// collection.
+        pub on_loaded: Option<fn(&mut World)>,
+    }
+
+    /// A screen's own clock, like [Time<Virtual>]. It advances with virtual
+    /// time, scaled by its relative speed, while the screen is Loading or
+    /// running its Update schedules. It stops in every other state, e.g. while
+    /// the screen is Paused, Unloading, or covered with [CoveredPolicy::Pause]
+    /// or [CoveredPolicy::RenderOnly]. While it is stopped, its delta is zero.
+    ///
+    /// While the screen's schedules run, [Time] reads this clock, so slowing
+    /// down one screen does not affect the others. The clock is reset
+    /// whenever the screen loads.
+    ///
+    /// The exception is [ScreenSchedule::FixedUpdate], where [Time] is still
+    /// the app's [Time<Fixed>]. The schedule keeps the fixed timestep, but
+    /// runs more or less often according to the relative speed.
+    #[derive(Debug, Clone, Copy)]
+    pub struct ScreenClock {
+        time: Time,
+        relative_speed: f64,
+        /// Fixed steps owed to the screen, see [ScreenClock::fixed_steps].
+        fixed_overstep: f64,
+    }
+    impl Default for ScreenClock {
+        fn default() -> Self {
+            Self {
+                time: Time::default(),
+                relative_speed: 1.,
+                fixed_overstep: 0.,
+            }
+        }
+    }
+    impl ScreenClock {
+        /// How much time has advanced since the last update.
+        pub fn delta(&self) -> std::time::Duration {
+            self.time.delta()
+        }
+        #[allow(missing_docs)]
+        pub fn delta_secs(&self) -> f32 {
+            self.time.delta_secs()
+        }
+        /// How much time has advanced since the screen loaded.
+        pub fn elapsed(&self) -> std::time::Duration {
+            self.time.elapsed()
+        }
+        #[allow(missing_docs)]
+        pub fn elapsed_secs(&self) -> f32 {
+            self.time.elapsed_secs()
+        }
+        /// The speed the clock advances at, relative to virtual time.
+        pub fn relative_speed(&self) -> f64 {
+            self.relative_speed
+        }
+        /// Sets the speed the clock advances at, relative to virtual time,
+        /// e.g. 0.5 for slow motion. Zero freezes the clock, while the
+        /// screen's systems keep running.
+        ///
+        /// # Panics
+        ///
+        /// Panics if `ratio` is negative or not finite.
+        pub fn set_relative_speed(&mut self, ratio: f64) {
+            assert!(ratio.is_finite(), "tried to go infinitely fast");
+            assert!(ratio >= 0., "tried to go back in time");
+            self.relative_speed = ratio;
+        }
+        /// The clock as a generic [Time].
+        pub fn as_time(&self) -> Time {
+            self.time
+        }
+        pub(crate) fn advance(&mut self, virtual_delta: std::time::Duration) {
+            self.time
+                .advance_by(virtual_delta.mul_f64(self.relative_speed));
+        }
+        /// How many times to run the screen's FixedUpdate schedule this fixed
+        /// step.
+        pub(crate) fn fixed_steps(&mut self) -> u32 {
+            self.fixed_overstep += self.relative_speed;
+            let steps = self.fixed_overstep.floor();
+            self.fixed_overstep -= steps;
+            steps as u32
+        }
+        pub(crate) fn reset(&mut self) {
+            *self = Self {
+                relative_speed: self.relative_speed,
+                ..Default::default()
+            };
+        }
+    }
+
+    #[derive(Debug)]
+    pub(crate) struct TransitionInfo {
+        pub transition: Arc<dyn ScreenTransition>,
+        /// The outgoing screens
+        pub from: Vec<ScreenId>,
+        /// The incoming screen
+        pub to: ScreenId,
+        pub phase: TransitionPhase,
+        pub elapsed: std::time::Duration,
+        pub started: bool,
+    }
+
     /// Data about a given screen. This is where all the screen's identifying information lives, including it's [ScreenState].
     #[derive(Debug)]
     pub struct ScreenInfo {
         /// Serialized name of the [Screen]
         name: String,
         state: ScreenState,
-        /// [TypeId] of the underlying [Screen] component
-        type_id: TypeId,
+        /// [TypeId] of the underlying [Screen] component, if it has one.
+        type_id: Option<TypeId>,
         /// [ScreenId] of the underlying [Screen] component
         screen_id: ScreenId,
         /// Indicates that the state has changed and needs to run the corresponding state schedule.
@@ -193,10 +860,48 @@ mod screens {
         pub(crate) initialized: bool,
         /// Should the Update schedule run even while loading?
         load_strategy: LoadStrategy,
+        /// What to do while another screen is pushed on top of this one.
+        covered_policy: CoveredPolicy,
+        /// Is another screen pushed on top of this one?
+        covered: bool,
         /// Initialize directly into Ready.
         skip_load: bool,
         /// Deinitialize immediately
         skip_unload: bool,
+        /// Do not record the screen in the [ScreenHistory].
+        skip_history: bool,
+        /// The screen's current [Screen::Params]
+        params: Box<dyn Reflect>,
+        /// [TypeId] of the screen's [Screen::Params]
+        params_type_id: TypeId,
+        default_params: fn() -> Box<dyn Reflect>,
+        params_from_reflect: fn(&dyn PartialReflect) -> Option<Box<dyn Reflect>>,
+        /// Played when switching to this screen.
+        transition: Option<Arc<dyn ScreenTransition>>,
+        /// Asked before switching away from this screen.
+        exit_guard: Option<SystemId<(), ExitDecision>>,
+        /// Inserted when the screen loads and removed on cleanup.
+        scoped_resources: Vec<ScopedResource>,
+        /// Spawned when the screen loads and despawned on cleanup.
+        scoped_observers: Vec<ScopedObserver>,
+        /// Have the scoped resources and observers been added?
+        pub(crate) scope_initialized: bool,
+        /// Assets which must load before the screen is Ready.
+        asset_trackers: Vec<AssetTracker>,
+        load_progress: ScreenLoadProgress,
+        /// The screen shown while this one loads.
+        loading_screen: Option<ScreenId>,
+        /// The loading screen which is currently shown on behalf of this one.
+        pub(crate) shown_loading_screen: Option<ScreenId>,
+        /// Is the screen loading in the background? See [PreloadScreen].
+        pub(crate) preloading: bool,
+        pub(crate) clock: ScreenClock,
+        /// Observers added when the screen was registered. Despawned when it
+        /// is unregistered.
+        pub(crate) registered_observers: Vec<Entity>,
+        /// Mirrors the screen into its [ScreenStateOf], see [ScreenStatesPlugin].
+        /// Not set for dynamic screens.
+        pub(crate) state_mirror: Option<fn(&mut App)>,
     }
     impl ScreenInfo {
         #[allow(missing_docs)]
@@ -204,14 +909,34 @@ mod screens {
             Self {
                 name: S::name(),
                 state: ScreenState::Unloaded,
-                type_id: TypeId::of::<S>(),
+                type_id: Some(TypeId::of::<S>()),
                 needs_update: true,
                 skip_load: true,
                 skip_unload: true,
+                skip_history: false,
                 load_strategy: LoadStrategy::Blocking,
+                covered_policy: CoveredPolicy::Run,
+                covered: false,
                 changed_at: tick,
                 initialized: false,
                 screen_id,
+                params: default_params::<S::Params>(),
+                params_type_id: TypeId::of::<S::Params>(),
+                default_params: default_params::<S::Params>,
+                params_from_reflect: params_from_reflect::<S::Params>,
+                transition: None,
+                exit_guard: None,
+                scoped_resources: vec![],
+                scoped_observers: vec![],
+                scope_initialized: false,
+                asset_trackers: vec![],
+                load_progress: ScreenLoadProgress::default(),
+                loading_screen: None,
+                shown_loading_screen: None,
+                preloading: false,
+                clock: ScreenClock::default(),
+                registered_observers: vec![],
+                state_mirror: None,
             }
         }
 
@@ -220,6 +945,9 @@ mod screens {
                 self.state,
                 ScreenState::Unloaded | ScreenState::Unloading | ScreenState::LoadQueued
             ) {
+                self.asset_trackers.clear();
+                self.load_progress = ScreenLoadProgress::default();
+                self.clock.reset();
                 if self.skip_load {
                     self.state = ScreenState::Ready
                 } else {
@@ -233,7 +961,16 @@ mod screens {
         /// Unloads the screen.
         /// Has no effect if already in Unloading or Unloaded states.
         pub fn unload(&mut self, tick: Tick) {
-            if matches!(self.state, ScreenState::Loading | ScreenState::Ready) {
+            self.preloading = false;
+            if matches!(self.state, ScreenState::Failed(_)) {
+                // Nothing to unload, but clean up what was spawned.
+                self.state = ScreenState::Cleanup;
+                self.needs_update = true;
+                self.changed_at = tick;
+            } else if matches!(
+                self.state,
+                ScreenState::Loading | ScreenState::Ready | ScreenState::Paused
+            ) {
                 if self.skip_unload {
                     self.state = ScreenState::Cleanup;
                 } else {
@@ -243,6 +980,38 @@ mod screens {
                 self.changed_at = tick;
             }
         }
+        /// Pauses the screen, see [PauseScreen].
+        /// Only works if in the `Ready` state.
+        pub fn pause(&mut self, tick: Tick) {
+            if matches!(self.state, ScreenState::Ready) {
+                // Not a lifecycle change, so OnReady does not run again.
+                self.state = ScreenState::Paused;
+                self.changed_at = tick;
+            }
+        }
+        /// Resumes the screen, see [ResumeScreen].
+        /// Only works if in the `Paused` state.
+        pub fn resume(&mut self, tick: Tick) {
+            if matches!(self.state, ScreenState::Paused) {
+                self.state = ScreenState::Ready;
+                self.changed_at = tick;
+            }
+        }
+        /// Loads the screen in the background, see [PreloadScreen].
+        /// Only works if in the `Unloaded` state.
+        pub fn preload(&mut self, tick: Tick) {
+            if matches!(self.state, ScreenState::Unloaded) {
+                self.preloading = true;
+                self.load(tick);
+            }
+        }
+        /// Cancels a preload, unloading the screen. See [CancelPreload].
+        /// Only works if the screen is preloading.
+        pub fn cancel_preload(&mut self, tick: Tick) {
+            if self.preloading {
+                self.unload(tick);
+            }
+        }
         /// Queues the screen to load, unloading any other screens and waiting
         /// until their cleanup is complete.
         /// Only works if in the `Unloaded` state.
@@ -272,8 +1041,20 @@ mod screens {
             }
         }
 
+        /// Fails loading the screen with the given reason.
+        /// Has no effect if the screen is not LoadQueued or Loading.
+        pub fn fail_loading(&mut self, reason: impl Into<Arc<str>>, tick: Tick) {
+            if matches!(self.state, ScreenState::LoadQueued | ScreenState::Loading) {
+                self.state = ScreenState::Failed(reason.into());
+                self.needs_update = true;
+                self.changed_at = tick;
+            }
+        }
+
         pub(crate) fn finish_cleanup(&mut self, tick: Tick) {
             if matches!(self.state, ScreenState::Cleanup) {
+                self.asset_trackers.clear();
+                self.scope_initialized = false;
                 self.state = ScreenState::Unloaded;
                 self.needs_update = true;
                 self.changed_at = tick;
@@ -285,6 +1066,142 @@ mod screens {
             self.load_strategy
         }
 
+        #[allow(missing_docs)]
+        pub fn covered_policy(&self) -> CoveredPolicy {
+            self.covered_policy
+        }
+
+        #[allow(missing_docs)]
+        pub fn set_covered_policy(&mut self, covered_policy: CoveredPolicy) {
+            self.covered_policy = covered_policy;
+        }
+
+        /// Is another screen pushed on top of this one? See [ScreenStack].
+        pub fn covered(&self) -> bool {
+            self.covered
+        }
+
+        pub(crate) fn set_covered(&mut self, covered: bool) {
+            self.covered = covered;
+        }
+
+        /// Should the screen's [ScreenSchedule::Update] and
+        /// [ScreenSchedule::FixedUpdate] schedules run? False while covered,
+        /// see [CoveredPolicy], or preloading. Does not consider the screen's
+        /// state.
+        pub fn runs_update(&self) -> bool {
+            !self.preloading && (!self.covered || matches!(self.covered_policy, CoveredPolicy::Run))
+        }
+
+        /// Is the screen loading in the background? See [PreloadScreen].
+        pub fn preloading(&self) -> bool {
+            self.preloading
+        }
+
+        /// The screen's own clock, see [ScreenClock].
+        pub fn clock(&self) -> &ScreenClock {
+            &self.clock
+        }
+
+        /// Mutable access to the screen's [ScreenClock], e.g. to slow it down.
+        pub fn clock_mut(&mut self) -> &mut ScreenClock {
+            &mut self.clock
+        }
+
+        /// The [ScreenTransition] played when switching to this screen.
+        pub fn transition(&self) -> Option<&Arc<dyn ScreenTransition>> {
+            self.transition.as_ref()
+        }
+
+        #[allow(missing_docs)]
+        pub fn set_transition(&mut self, transition: Option<Arc<dyn ScreenTransition>>) {
+            self.transition = transition;
+        }
+
+        /// The system asked before switching away from this screen. See
+        /// [ScreenScopeBuilder::with_exit_guard].
+        pub fn exit_guard(&self) -> Option<SystemId<(), ExitDecision>> {
+            self.exit_guard
+        }
+
+        #[allow(missing_docs)]
+        pub fn set_exit_guard(&mut self, exit_guard: Option<SystemId<(), ExitDecision>>) {
+            self.exit_guard = exit_guard;
+        }
+
+        pub(crate) fn scoped_resources(&self) -> &[ScopedResource] {
+            &self.scoped_resources
+        }
+
+        pub(crate) fn set_scoped_resources(&mut self, scoped_resources: Vec<ScopedResource>) {
+            self.scoped_resources = scoped_resources;
+        }
+
+        pub(crate) fn scoped_observers(&self) -> &[ScopedObserver] {
+            &self.scoped_observers
+        }
+
+        pub(crate) fn set_scoped_observers(&mut self, scoped_observers: Vec<ScopedObserver>) {
+            self.scoped_observers = scoped_observers;
+        }
+
+        /// The screen shown while this one loads. See
+        /// [ScreenScopeBuilder::with_loading_screen].
+        pub fn loading_screen(&self) -> Option<ScreenId> {
+            self.loading_screen
+        }
+
+        #[allow(missing_docs)]
+        pub fn set_loading_screen(&mut self, loading_screen: Option<ScreenId>) {
+            self.loading_screen = loading_screen;
+        }
+
+        /// How many of the screen's tracked assets have loaded. Updated every
+        /// frame while the screen is [ScreenState::Loading].
+        pub fn load_progress(&self) -> ScreenLoadProgress {
+            self.load_progress
+        }
+
+        /// Is the screen waiting on any tracked assets? If so, it will finish
+        /// loading by itself once they have all loaded.
+        pub fn tracks_assets(&self) -> bool {
+            !self.asset_trackers.is_empty()
+        }
+
+        pub(crate) fn add_asset_tracker(&mut self, tracker: AssetTracker) {
+            self.load_progress.total += tracker.handles.len();
+            self.asset_trackers.push(tracker);
+        }
+
+        /// Recounts the loaded assets. Assets which were not loaded through
+        /// the [AssetServer], e.g. those added with [Assets::add], count as
+        /// loaded.
+        pub(crate) fn update_load_progress(
+            &mut self,
+            asset_server: &AssetServer,
+        ) -> ScreenLoadProgress {
+            let handles = self.asset_trackers.iter().flat_map(|t| t.handles.iter());
+            self.load_progress = ScreenLoadProgress {
+                done: handles
+                    .clone()
+                    .filter(|handle| {
+                        asset_server.get_load_state(handle.id()).is_none()
+                            || asset_server.is_loaded_with_dependencies(handle.id())
+                    })
+                    .count(),
+                total: handles.count(),
+            };
+            self.load_progress
+        }
+
+        /// Takes the callbacks to run once every tracked asset has loaded.
+        pub(crate) fn take_on_loaded(&mut self) -> Vec<fn(&mut World)> {
+            self.asset_trackers
+                .iter_mut()
+                .filter_map(|t| t.on_loaded.take())
+                .collect()
+        }
+
         #[allow(missing_docs)]
         pub fn skip_load(&self) -> bool {
             self.skip_load
@@ -295,6 +1212,16 @@ mod screens {
             self.skip_unload
         }
 
+        #[allow(missing_docs)]
+        pub fn skip_history(&self) -> bool {
+            self.skip_history
+        }
+
+        #[allow(missing_docs)]
+        pub fn set_skip_history(&mut self, skip_history: bool) {
+            self.skip_history = skip_history;
+        }
+
         #[allow(missing_docs)]
         pub fn set_skip_unload(&mut self, skip_unload: bool) {
             self.skip_unload = skip_unload;
@@ -315,7 +1242,8 @@ mod screens {
             self.initialized
         }
 
-        #[allow(missing_docs)]
+        /// The tick at which the screen's state last changed, or at which its
+        /// state schedules last ran.
         pub fn changed_at(&self) -> Tick {
             self.changed_at
         }
@@ -325,14 +1253,23 @@ mod screens {
             self.needs_update
         }
 
-        #[allow(missing_docs)]
-        pub fn type_id(&self) -> TypeId {
+        /// [TypeId] of the underlying [Screen] component. `None` for dynamic
+        /// screens, see [RegisterScreen::register_dynamic_screen].
+        pub fn type_id(&self) -> Option<TypeId> {
             self.type_id
         }
 
         #[allow(missing_docs)]
         pub fn state(&self) -> ScreenState {
-            self.state
+            self.state.clone()
+        }
+
+        /// Why the screen failed to load, if it is [ScreenState::Failed].
+        pub fn failure(&self) -> Option<&str> {
+            match &self.state {
+                ScreenState::Failed(reason) => Some(reason.as_ref()),
+                _ => None,
+            }
         }
 
         #[allow(missing_docs)]
@@ -340,10 +1277,86 @@ mod screens {
             &self.name
         }
 
+        /// Turns this into the info of a dynamic screen with the given name.
+        pub(crate) fn set_dynamic(&mut self, name: String) {
+            self.name = name;
+            self.type_id = None;
+        }
+
         #[allow(missing_docs)]
         pub fn screen_id(&self) -> ScreenId {
             self.screen_id
         }
+
+        /// The key of the screen's schedules, see [ScreenKey].
+        pub fn key(&self) -> ScreenKey {
+            self.type_id
+                .map_or(ScreenKey::Id(self.screen_id), ScreenKey::Type)
+        }
+
+        /// The screen's current [Screen::Params]. Prefer [ScreenParams] for typed access.
+        pub fn params(&self) -> &dyn Reflect {
+            self.params.as_ref()
+        }
+
+        /// [TypeId] of the screen's [Screen::Params]
+        pub fn params_type_id(&self) -> TypeId {
+            self.params_type_id
+        }
+
+        /// Sets the screen's params from any value convertible to its
+        /// [Screen::Params]. If `None`, resets the params to their default.
+        pub fn set_params(
+            &mut self,
+            params: Option<&dyn PartialReflect>,
+        ) -> Result<(), ScreenError> {
+            self.params = self.convert_params(params)?;
+            Ok(())
+        }
+
+        /// Checks that the params are convertible to the screen's
+        /// [Screen::Params], without setting them.
+        pub fn check_params(&self, params: Option<&dyn PartialReflect>) -> Result<(), ScreenError> {
+            self.convert_params(params).map(|_| ())
+        }
+
+        fn convert_params(
+            &self,
+            params: Option<&dyn PartialReflect>,
+        ) -> Result<Box<dyn Reflect>, ScreenError> {
+            match params {
+                Some(params) => (self.params_from_reflect)(params).ok_or_else(|| {
+                    ScreenError::InvalidParams(
+                        self.name.clone(),
+                        format!("expected {}", self.params.reflect_type_path()),
+                    )
+                }),
+                None => Ok((self.default_params)()),
+            }
+        }
+
+        /// Parses the screen's [Screen::Params] from a [RON](ron) string.
+        /// The params type must be registered, which happens automatically when
+        /// the screen is registered.
+        pub fn parse_params(
+            &self,
+            registry: &TypeRegistry,
+            params: &str,
+        ) -> Result<Box<dyn PartialReflect>, ScreenError> {
+            use serde::de::DeserializeSeed;
+            let err = |e: String| ScreenError::InvalidParams(self.name.clone(), e);
+            let registration = registry.get(self.params_type_id).ok_or_else(|| {
+                err(format!(
+                    "{} is not registered",
+                    self.params.reflect_type_path()
+                ))
+            })?;
+            let mut deserializer =
+                ron::Deserializer::from_str(params).map_err(|e| err(e.to_string()))?;
+            bevy::reflect::serde::TypedReflectDeserializer::new(registration, registry)
+                .deserialize(&mut deserializer)
+                .map_err(|e| err(e.to_string()))
+        }
     }
 }
 pub use screens::*;
@@ -363,6 +1376,9 @@ mod schedules {
         Loading,
         /// Runs on [Update] when the screen has [ScreenState::Unloading]
         Unloading,
+        /// Runs on [Update] when the screen has [ScreenState::Paused], or is
+        /// covered with [CoveredPolicy::Pause]
+        Paused,
         /// For internal use! Runs after [ScreenState::Unloading]. Used to clean up screen-scoped entities.
         Cleanup,
         /// Can also be specified as [on_screen_load]
@@ -373,33 +1389,59 @@ mod schedules {
         OnUnload,
         /// Can also be specified as [on_screen_unloaded]
         OnUnloaded,
+        /// Can also be specified as [on_screen_failed]
+        OnFailed,
     }
 
-    // TODO: This should use ScreenId internally.
-    /// Wrapper around [ScreenSchedule]. Needed to make schedules unique per type.
+    /// Wrapper around [ScreenSchedule]. Needed to make schedules unique per screen.
     #[derive(ScheduleLabel, Debug, Clone, Copy, PartialEq, Eq, Hash)]
     pub struct ScreenScheduleLabel {
-        id: TypeId,
+        key: ScreenKey,
         kind: ScreenSchedule,
     }
     impl ScreenScheduleLabel {
         #[allow(missing_docs)]
         pub fn new<S: Screen>(kind: ScreenSchedule) -> Self {
-            Self {
-                id: TypeId::of::<S>(),
-                kind,
-            }
+            Self::from_key(kind, ScreenKey::of::<S>())
         }
-        #[allow(missing_docs)]
-        pub fn from_id(kind: ScreenSchedule, id: TypeId) -> Self {
-            Self { id, kind }
+        /// The label of the schedule for the screen with the given key, see
+        /// [ScreenInfo::key].
+        pub fn from_key(kind: ScreenSchedule, key: ScreenKey) -> Self {
+            Self { key, kind }
         }
     }
+
+    /// System sets for ordering systems relative to the screen lifecycle
+    /// without knowing the concrete screen types, e.g. from service plugins.
+    ///
+    /// [ScreenSet::Lifecycle] holds the systems which drive the screens in
+    /// [Update], [FixedUpdate] and [PostUpdate]. The other sets are configured
+    /// in [Update] and [FixedUpdate] to run after it, and only while their
+    /// condition holds.
+    ///
+    /// ```rust,ignore
+    /// app.add_systems(Update, move_player.in_set(ScreenSet::Ready));
+    /// ```
+    #[derive(SystemSet, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub enum ScreenSet {
+        /// The systems which run the screens' schedules and handle switches.
+        Lifecycle,
+        /// Runs while any screen is loading, see [any_screen_loading].
+        Loading,
+        /// Runs while the [CurrentScreen] is ready, see
+        /// [current_screen_has_state].
+        Ready,
+        /// Runs while the [CurrentScreen] is paused, see
+        /// [current_screen_has_state].
+        Paused,
+        /// Runs while any screen is unloading, see [any_screen_unloading].
+        Unloading,
+    }
 }
 pub use schedules::*;
 
 /// Describes the current state of a screen. Not an actual [State].
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
 pub enum ScreenState {
     #[default]
     /// The screen is currently down.
@@ -410,17 +1452,48 @@ pub enum ScreenState {
     Loading,
     /// The screen is fully loaded and ready to execute its main systems.
     Ready,
+    /// The screen is loaded, but its main systems are paused. See
+    /// [PauseScreen].
+    Paused,
     /// The screen is running any custom unload systems.
     Unloading,
     /// For internal use. Cleaning up screen-scoped entities.
     Cleanup,
+    /// The screen failed to load, see [FailLoading]. Holds the reason.
+    /// Switching away from the screen cleans it up.
+    Failed(Arc<str>),
 }
 
 mod system_params {
-    use bevy::ecs::change_detection::Tick;
+    use bevy::ecs::{change_detection::Tick, system::SystemParamValidationError};
 
     use super::*;
 
+    /// Skips systems with a param for the screen `S` while `S` is not
+    /// registered, e.g. after [RegisterScreen::unregister_screen], instead of
+    /// panicking.
+    ///
+    /// # Safety
+    /// The caller must be allowed to read [ScreenRegistry] and [ScreenData].
+    unsafe fn validate_registered<S: Screen, P>(
+        world: bevy::ecs::world::unsafe_world_cell::UnsafeWorldCell,
+    ) -> Result<(), SystemParamValidationError> {
+        let registry = unsafe { world.get_resource::<ScreenRegistry>() };
+        let data = unsafe { world.get_resource::<ScreenData>() };
+        let registered = registry
+            .and_then(|registry| registry.get(&TypeId::of::<S>()).ok())
+            .zip(data)
+            .is_some_and(|(id, data)| data.get(id).is_ok());
+        if registered {
+            Ok(())
+        } else {
+            Err(SystemParamValidationError::skipped::<P>(format!(
+                "{} is not registered",
+                S::name()
+            )))
+        }
+    }
+
     /// Read-only [SystemParam] for easy access to a screen's [ScreenInfo]
     pub struct ScreenInfoRef<'w, S: Screen> {
         data: &'w ScreenInfo,
@@ -449,6 +1522,14 @@ mod system_params {
                 .add_unfiltered_resource_read(world.resource_id::<ScreenRegistry>().unwrap());
         }
 
+        unsafe fn validate_param(
+            _state: &mut Self::State,
+            _system_meta: &bevy::ecs::system::SystemMeta,
+            world: bevy::ecs::world::unsafe_world_cell::UnsafeWorldCell,
+        ) -> Result<(), SystemParamValidationError> {
+            unsafe { validate_registered::<S, Self>(world) }
+        }
+
         unsafe fn get_param<'world, 'state>(
             _state: &'state mut Self::State,
             _system_meta: &bevy::ecs::system::SystemMeta,
@@ -488,19 +1569,65 @@ mod system_params {
             let tick = self.change_tick;
             self.data.unload(tick);
         }
+        /// Loads the screen in the background. Has no effect if the screen is
+        /// not Unloaded. See [PreloadScreen].
+        pub fn preload(&mut self) {
+            let tick = self.change_tick;
+            self.data.preload(tick);
+        }
+        /// Pauses the screen. Has no effect if the screen is not Ready.
+        /// See [PauseScreen].
+        pub fn pause(&mut self) {
+            let tick = self.change_tick;
+            self.data.pause(tick);
+        }
+        /// Resumes the screen. Has no effect if the screen is not Paused.
+        /// See [ResumeScreen].
+        pub fn resume(&mut self) {
+            let tick = self.change_tick;
+            self.data.resume(tick);
+        }
+        /// Sets the relative speed of the screen's [ScreenClock].
+        pub fn set_relative_speed(&mut self, ratio: f64) {
+            self.data.clock.set_relative_speed(ratio);
+        }
+        /// Cancels a preload. Has no effect if the screen is not preloading.
+        /// See [CancelPreload].
+        pub fn cancel_preload(&mut self) {
+            let tick = self.change_tick;
+            self.data.cancel_preload(tick);
+        }
         /// Loads the screen. Has no effect if the screen is not Loading.
         pub fn finish_loading(&mut self) {
             let tick = self.change_tick;
             self.data.finish_loading(tick);
         }
+        /// Fails loading the screen. Has no effect if the screen is not Loading.
+        /// See [FailLoading].
+        pub fn fail_loading(&mut self, reason: impl ToString) {
+            let tick = self.change_tick;
+            self.data.fail_loading(reason.to_string(), tick);
+        }
         /// Loads the screen. Has no effect if the screen is not Loading.
         pub fn finish_unloading(&mut self) {
             let tick = self.change_tick;
             self.data.finish_unloading(tick);
         }
-        pub(crate) fn finish_cleanup(&mut self) {
-            let tick = self.change_tick;
-            self.data.finish_cleanup(tick);
+        /// Waits for the given assets before finishing loading. Once every
+        /// tracked asset has loaded with its dependencies, the screen will
+        /// become Ready by itself. Progress can be read with
+        /// [ScreenInfo::load_progress].
+        ///
+        /// Call this from [ScreenSchedule::OnLoad], as tracked assets are
+        /// cleared whenever the screen starts loading.
+        pub fn track_assets(
+            &mut self,
+            handles: impl IntoIterator<Item = impl Into<UntypedHandle>>,
+        ) {
+            self.data.add_asset_tracker(AssetTracker {
+                handles: handles.into_iter().map(Into::into).collect(),
+                on_loaded: None,
+            });
         }
         #[allow(missing_docs)]
         pub fn data(&self) -> &ScreenInfo {
@@ -523,6 +1650,14 @@ mod system_params {
                 .add_unfiltered_resource_write(world.resource_id::<ScreenRegistry>().unwrap());
         }
 
+        unsafe fn validate_param(
+            _state: &mut Self::State,
+            _system_meta: &bevy::ecs::system::SystemMeta,
+            world: bevy::ecs::world::unsafe_world_cell::UnsafeWorldCell,
+        ) -> Result<(), SystemParamValidationError> {
+            unsafe { validate_registered::<S, Self>(world) }
+        }
+
         unsafe fn get_param<'world, 'state>(
             _state: &'state mut Self::State,
             _system_meta: &bevy::ecs::system::SystemMeta,
@@ -544,6 +1679,95 @@ mod system_params {
         }
     }
 
+    /// Read-only [SystemParam] for the given screen's current [Screen::Params].
+    /// These are set whenever the screen is switched to, see [SwitchToScreen].
+    #[derive(SystemParam)]
+    pub struct ScreenParams<'w, S: Screen> {
+        info: ScreenInfoRef<'w, S>,
+    }
+    impl<'w, S: Screen> ScreenParams<'w, S> {
+        #[allow(missing_docs)]
+        pub fn get(&self) -> &'w S::Params {
+            self.info
+                .data()
+                .params()
+                .downcast_ref()
+                .expect("Screen params should match the screen's Params type")
+        }
+    }
+    impl<'w, S: Screen> std::ops::Deref for ScreenParams<'w, S> {
+        type Target = S::Params;
+        fn deref(&self) -> &Self::Target {
+            self.get()
+        }
+    }
+
+    /// [SystemParam] for a resource scoped to the screen `S`, see
+    /// [ScreenScopeBuilder::init_resource]. Unlike [Res], accessing the
+    /// resource while the screen is not loaded returns an error instead of
+    /// failing the system, even if another resource of the same type exists.
+    #[derive(SystemParam)]
+    pub struct ScreenRes<'w, S: Screen, R: Resource> {
+        res: Option<Res<'w, R>>,
+        info: ScreenInfoRef<'w, S>,
+    }
+    impl<'w, S: Screen, R: Resource> ScreenRes<'w, S, R> {
+        #[allow(missing_docs)]
+        pub fn get(&self) -> Result<&R, ScreenError> {
+            let info = self.info.data();
+            self.res
+                .as_deref()
+                .filter(|_| scoped_resource_live(info))
+                .ok_or_else(|| scoped_resource_error::<S, R>(info))
+        }
+    }
+
+    /// Mutable version of [ScreenRes].
+    #[derive(SystemParam)]
+    pub struct ScreenResMut<'w, S: Screen, R: Resource> {
+        res: Option<ResMut<'w, R>>,
+        info: ScreenInfoRef<'w, S>,
+    }
+    impl<'w, S: Screen, R: Resource> ScreenResMut<'w, S, R> {
+        #[allow(missing_docs)]
+        pub fn get(&self) -> Result<&R, ScreenError> {
+            let info = self.info.data();
+            self.res
+                .as_deref()
+                .filter(|_| scoped_resource_live(info))
+                .ok_or_else(|| scoped_resource_error::<S, R>(info))
+        }
+        #[allow(missing_docs)]
+        pub fn get_mut(&mut self) -> Result<&mut R, ScreenError> {
+            let info = self.info.data();
+            self.res
+                .as_deref_mut()
+                .filter(|_| scoped_resource_live(info))
+                .ok_or_else(|| scoped_resource_error::<S, R>(info))
+        }
+    }
+
+    /// Whether the screen's scoped resources are inserted, i.e. from when it
+    /// starts loading until it is cleaned up. Otherwise a resource of the same
+    /// type belongs to someone else.
+    fn scoped_resource_live(info: &ScreenInfo) -> bool {
+        matches!(
+            info.state(),
+            ScreenState::Loading
+                | ScreenState::Ready
+                | ScreenState::Paused
+                | ScreenState::Unloading
+        )
+    }
+
+    fn scoped_resource_error<S: Screen, R: Resource>(info: &ScreenInfo) -> ScreenError {
+        ScreenError::ScopedResourceUnavailable(
+            std::any::type_name::<R>().to_owned(),
+            S::name(),
+            info.state(),
+        )
+    }
+
     /// Gets the [ScreenId] for the given [Screen]
     #[derive(Debug, Copy, Clone, Deref)]
     pub struct ScreenIdFor<S: Screen> {
@@ -565,6 +1789,14 @@ mod system_params {
         ) {
         }
 
+        unsafe fn validate_param(
+            _state: &mut Self::State,
+            _system_meta: &bevy::ecs::system::SystemMeta,
+            world: bevy::ecs::world::unsafe_world_cell::UnsafeWorldCell,
+        ) -> Result<(), SystemParamValidationError> {
+            unsafe { validate_registered::<S, Self>(world) }
+        }
+
         unsafe fn get_param<'world, 'state>(
             _state: &'state mut Self::State,
             _system_meta: &bevy::ecs::system::SystemMeta,
@@ -684,6 +1916,10 @@ mod helpers {
     pub fn screen_ready<S: Screen>() -> impl FnMut(ScreenInfoRef<S>) -> bool + Clone {
         |data: ScreenInfoRef<S>| matches!(data.data().state(), ScreenState::Ready)
     }
+    /// Is the screen paused? See [PauseScreen].
+    pub fn screen_paused<S: Screen>() -> impl FnMut(ScreenInfoRef<S>) -> bool + Clone {
+        |data: ScreenInfoRef<S>| matches!(data.data().state(), ScreenState::Paused)
+    }
     /// Is the screen currently unloading?
     pub fn screen_unloading<S: Screen>() -> impl FnMut(ScreenInfoRef<S>) -> bool + Clone {
         |data: ScreenInfoRef<S>| matches!(data.data().state(), ScreenState::Unloading)
@@ -692,57 +1928,142 @@ mod helpers {
     pub fn screen_unloaded<S: Screen>() -> impl FnMut(ScreenInfoRef<S>) -> bool + Clone {
         |data: ScreenInfoRef<S>| matches!(data.data().state(), ScreenState::Unloaded)
     }
+    /// Did the screen fail to load?
+    pub fn screen_failed<S: Screen>() -> impl FnMut(ScreenInfoRef<S>) -> bool + Clone {
+        |data: ScreenInfoRef<S>| matches!(data.data().state(), ScreenState::Failed(_))
+    }
+
+    /// Condition, like [screen_has_state], for a screen given by name. Useful
+    /// for dynamic screens, or when the screen type is not known.
+    pub fn screen_named_has_state(
+        name: impl Into<String>,
+        state: ScreenState,
+    ) -> impl FnMut(Res<ScreenData>) -> bool + Clone {
+        let name = name.into();
+        move |data: Res<ScreenData>| {
+            data.iter_some()
+                .any(|info| info.name() == name && info.state() == state)
+        }
+    }
+    /// Condition, like [screen_has_state], for a screen given by [ScreenId].
+    pub fn screen_id_has_state(
+        id: ScreenId,
+        state: ScreenState,
+    ) -> impl FnMut(Res<ScreenData>) -> bool + Clone {
+        move |data: Res<ScreenData>| data.get(id).is_ok_and(|info| info.state() == state)
+    }
+    /// Is any screen loading? Preloading screens count as well.
+    pub fn any_screen_loading() -> impl FnMut(Res<ScreenData>) -> bool + Clone {
+        |data: Res<ScreenData>| {
+            data.iter_some()
+                .any(|info| matches!(info.state(), ScreenState::Loading))
+        }
+    }
+    /// Is any screen unloading?
+    pub fn any_screen_unloading() -> impl FnMut(Res<ScreenData>) -> bool + Clone {
+        |data: Res<ScreenData>| {
+            data.iter_some()
+                .any(|info| matches!(info.state(), ScreenState::Unloading))
+        }
+    }
+    /// Is the [CurrentScreen] the screen with the given name?
+    pub fn current_screen_is(
+        name: impl Into<String>,
+    ) -> impl FnMut(Res<CurrentScreen>, Res<ScreenData>) -> bool + Clone {
+        let name = name.into();
+        move |current: Res<CurrentScreen>, data: Res<ScreenData>| {
+            current
+                .get_id()
+                .and_then(|id| data.get(id).ok())
+                .is_some_and(|info| info.name() == name)
+        }
+    }
+    /// Is the [CurrentScreen] the screen with the given [ScreenId]?
+    pub fn current_screen_is_id(id: ScreenId) -> impl FnMut(Res<CurrentScreen>) -> bool + Clone {
+        move |current: Res<CurrentScreen>| current.get_id() == Some(id)
+    }
+    /// Does the [CurrentScreen] have the given state?
+    pub fn current_screen_has_state(
+        state: ScreenState,
+    ) -> impl FnMut(Res<CurrentScreen>, Res<ScreenData>) -> bool + Clone {
+        move |current: Res<CurrentScreen>, data: Res<ScreenData>| {
+            current
+                .get_id()
+                .and_then(|id| data.get(id).ok())
+                .is_some_and(|info| info.state() == state)
+        }
+    }
+    /// Has any screen changed state since the system last ran? See
+    /// [ScreenInfo::changed_at].
+    pub fn screen_state_changed() -> impl FnMut(Res<ScreenData>, SystemChangeTick) -> bool + Clone {
+        |data: Res<ScreenData>, tick: SystemChangeTick| {
+            data.iter_some().any(|info| {
+                info.changed_at()
+                    .is_newer_than(tick.last_run(), tick.this_run())
+            })
+        }
+    }
 
     /// Label of a schedule which fires when the screen has begun to load.
     #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone, Copy)]
-    pub struct OnScreenLoad(pub TypeId);
+    pub struct OnScreenLoad(pub ScreenKey);
 
     /// See [OnScreenLoad]
     pub fn on_screen_load<S: Screen>() -> impl ScheduleLabel {
-        OnScreenLoad(TypeId::of::<S>())
+        OnScreenLoad(ScreenKey::of::<S>())
     }
 
     /// Label of a schedule which fires when the screen has begun its cleanup schedule.
     /// Try to avoid spawning anything during this schedule.
     #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone, Copy)]
-    pub struct OnScreenCleanup(pub TypeId);
+    pub struct OnScreenCleanup(pub ScreenKey);
     /// See [OnScreenCleanup]
     pub fn on_screen_cleanup<S: Screen>() -> impl ScheduleLabel {
-        OnScreenCleanup(TypeId::of::<S>())
+        OnScreenCleanup(ScreenKey::of::<S>())
     }
     /// Label of a schedule which fires when the screen is waiting to load.
     #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone, Copy)]
-    pub struct OnScreenLoadQueued(pub TypeId);
+    pub struct OnScreenLoadQueued(pub ScreenKey);
     /// See [OnScreenLoadQueued]
     pub fn on_screen_load_queued<S: Screen>() -> impl ScheduleLabel {
-        OnScreenLoadQueued(TypeId::of::<S>())
+        OnScreenLoadQueued(ScreenKey::of::<S>())
     }
 
     /// Label of a schedule which fires when the screen has finished loading.
     #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone, Copy)]
-    pub struct OnScreenReady(pub TypeId);
+    pub struct OnScreenReady(pub ScreenKey);
 
     /// See [OnScreenReady]
     pub fn on_screen_ready<S: Screen>() -> impl ScheduleLabel {
-        OnScreenReady(TypeId::of::<S>())
+        OnScreenReady(ScreenKey::of::<S>())
     }
 
     /// Label of a schedule which fires when the screen is beginning to unload. Not to be confused with [OnScreenUnloaded].
     #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone, Copy)]
-    pub struct OnScreenUnload(pub TypeId);
+    pub struct OnScreenUnload(pub ScreenKey);
 
     /// See [OnScreenUnload]
     pub fn on_screen_unload<S: Screen>() -> impl ScheduleLabel {
-        OnScreenUnload(TypeId::of::<S>())
+        OnScreenUnload(ScreenKey::of::<S>())
     }
 
     /// Label of a schedule which fires when the screen has finished unloading and is no longer active.
     #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone, Copy)]
-    pub struct OnScreenUnloaded(pub TypeId);
+    pub struct OnScreenUnloaded(pub ScreenKey);
 
     /// See [OnScreenUnloaded]
     pub fn on_screen_unloaded<S: Screen>() -> impl ScheduleLabel {
-        OnScreenUnloaded(TypeId::of::<S>())
+        OnScreenUnloaded(ScreenKey::of::<S>())
+    }
+
+    /// Label of a schedule which fires when the screen has failed to load.
+    /// See [FailLoading].
+    #[derive(ScheduleLabel, Debug, PartialEq, Eq, Hash, Clone, Copy)]
+    pub struct OnScreenFailed(pub ScreenKey);
+
+    /// See [OnScreenFailed]
+    pub fn on_screen_failed<S: Screen>() -> impl ScheduleLabel {
+        OnScreenFailed(ScreenKey::of::<S>())
     }
 }
 pub use helpers::*;
@@ -0,0 +1,154 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+pub use crate::prelude::*;
+use bevy::asset::{AssetLoader, LoadContext, io::Reader};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// The allowed switches between screens, by [Screen::name]. While this
+/// resource exists, [SwitchToScreen] and [PushScreen] are rejected with
+/// [ScreenError::IllegalSwitch] unless the [CurrentScreen] has an edge to the
+/// target. Switching to the current screen is always allowed, as are forced
+/// switches (see [SwitchToScreen::forced]) and [PopScreen]. Note that
+/// [GoBack] is an ordinary switch, so it needs an edge as well.
+///
+/// Build the graph with [Self::allow], or load it from RON, either with
+/// [Self::from_ron] or as an asset through [ScreenFlowHandle]. In RON, the
+/// graph is a map from each screen to the screens it may switch to:
+///
+/// ```ron
+/// {
+///     "SplashScreen": ["MainMenuScreen"],
+///     "MainMenuScreen": ["WorldScreen"],
+///     "WorldScreen": ["MainMenuScreen", "PauseScreen"],
+/// }
+/// ```
+///
+/// Once the graph changes, it is checked against the registered screens, and
+/// any unknown or unreachable screens are reported.
+#[derive(
+    Resource, Asset, TypePath, Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize,
+)]
+#[serde(transparent)]
+pub struct ScreenFlow(BTreeMap<String, BTreeSet<String>>);
+
+impl ScreenFlow {
+    /// Parses the graph from RON, see [ScreenFlow].
+    pub fn from_ron(ron: &str) -> Result<Self, ron::error::SpannedError> {
+        ron::from_str(ron)
+    }
+    /// Allows switching from `A` to `B`.
+    pub fn allow<A: Screen, B: Screen>(self) -> Self {
+        self.allow_by_name(A::name(), B::name())
+    }
+    /// Allows switching from the screen named `from` to the one named `to`,
+    /// e.g. for dynamic screens.
+    pub fn allow_by_name(mut self, from: impl Into<String>, to: impl Into<String>) -> Self {
+        self.0.entry(from.into()).or_default().insert(to.into());
+        self
+    }
+    /// Can the screen named `from` switch to the one named `to`?
+    pub fn is_allowed(&self, from: &str, to: &str) -> bool {
+        from == to || self.0.get(from).is_some_and(|targets| targets.contains(to))
+    }
+    /// Checks a switch between two screens, see [Self::is_allowed].
+    pub fn check(&self, from: &ScreenInfo, to: &ScreenInfo) -> Result<(), ScreenError> {
+        if self.is_allowed(from.name(), to.name()) {
+            Ok(())
+        } else {
+            Err(ScreenError::IllegalSwitch(
+                from.name().to_owned(),
+                to.name().to_owned(),
+            ))
+        }
+    }
+    /// The screens which `from` may switch to.
+    pub fn targets(&self, from: &str) -> impl Iterator<Item = &str> {
+        self.0.get(from).into_iter().flatten().map(String::as_str)
+    }
+    /// Every screen mentioned in the graph.
+    pub fn screens(&self) -> BTreeSet<&str> {
+        self.0
+            .iter()
+            .flat_map(|(from, targets)| std::iter::once(from).chain(targets))
+            .map(String::as_str)
+            .collect()
+    }
+    /// The given screens which cannot be reached from any of the `roots`,
+    /// e.g. the [InitialScreen].
+    pub fn unreachable<'a>(
+        &self,
+        roots: impl IntoIterator<Item = &'a str>,
+        screens: impl IntoIterator<Item = &'a str>,
+    ) -> Vec<&'a str> {
+        let mut reached = BTreeSet::new();
+        let mut queue = roots
+            .into_iter()
+            .map(str::to_owned)
+            .collect::<VecDeque<_>>();
+        while let Some(screen) = queue.pop_front() {
+            if !reached.contains(&screen) {
+                queue.extend(self.targets(&screen).map(str::to_owned));
+                reached.insert(screen);
+            }
+        }
+        screens
+            .into_iter()
+            .filter(|screen| !reached.contains(*screen))
+            .collect()
+    }
+    /// Exports the graph in the DOT format, e.g. for documentation.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph ScreenFlow {\n");
+        for (from, targets) in self.0.iter() {
+            if targets.is_empty() {
+                dot.push_str(&format!("    {from:?};\n"));
+            }
+            for to in targets {
+                dot.push_str(&format!("    {from:?} -> {to:?};\n"));
+            }
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Loads a [ScreenFlow] asset. Once it has loaded, and whenever it is
+/// modified, it replaces the [ScreenFlow] resource.
+#[derive(Resource, Debug, Clone, Deref)]
+pub struct ScreenFlowHandle(pub Handle<ScreenFlow>);
+
+/// Loads `.flow.ron` files as [ScreenFlow] assets.
+#[derive(Default, Debug, TypePath)]
+pub struct ScreenFlowLoader;
+
+/// Why a [ScreenFlow] asset could not be loaded.
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum ScreenFlowLoaderError {
+    #[error("Could not read screen flow: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse screen flow: {0}")]
+    Ron(#[from] ron::error::SpannedError),
+}
+
+impl AssetLoader for ScreenFlowLoader {
+    type Asset = ScreenFlow;
+    type Settings = ();
+    type Error = ScreenFlowLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &Self::Settings,
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = vec![];
+        reader.read_to_end(&mut bytes).await?;
+        Ok(ron::de::from_bytes(&bytes)?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["flow.ron"]
+    }
+}
@@ -1,5 +1,6 @@
 #![doc = include_str!("../README.md")]
 #![feature(register_tool)]
+#![feature(associated_type_defaults)]
 #![register_tool(bevy)]
 #![allow(bevy::panicking_methods)]
 #![deny(missing_docs)]
@@ -8,22 +9,39 @@
 use prelude::*;
 /// Resources, components, states, etc.
 pub mod data;
+/// The [ScreenFlow] graph and its asset loader.
+pub mod flow;
+/// Lifecycle statistics, see [ScreenMetrics].
+pub mod metrics;
+/// Saving and restoring the [CurrentScreen], see [ScreenPersistencePlugin].
+pub mod persist;
 mod plugin;
 /// The [ScreenScopeBuilder] and friends.
 pub mod scope;
+/// Bridge between screens and Bevy [States].
+pub mod states;
 mod systems;
 /// The [Screen] trait.
 pub mod trait_impl;
+/// The [ScreenTransition] trait and built-in transitions.
+pub mod transition;
 
 /// The main export.
 pub mod prelude {
     pub use super::data::*;
+    pub use super::flow::*;
+    pub use super::metrics::*;
+    pub use super::persist::*;
     pub use super::plugin::*;
     pub use super::scope::*;
+    pub use super::states::*;
     pub(crate) use super::systems::*;
     pub use super::trait_impl::*;
+    pub use super::transition::*;
     pub(crate) use bevy::prelude::*;
     pub(crate) use itertools::Itertools;
+    #[cfg(feature = "derive")]
+    pub use q_screens_derive::Screen;
     pub(crate) use std::marker::PhantomData;
     pub(crate) use tiny_bail::prelude::*;
 }
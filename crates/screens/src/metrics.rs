@@ -0,0 +1,152 @@
+use std::{any::TypeId, time::Duration};
+
+use bevy::{platform::collections::HashMap, utils::TypeIdMap};
+
+pub use crate::prelude::*;
+
+/// Timing and lifecycle statistics for every screen, keyed by [ScreenId].
+///
+/// The screens' states are sampled once per frame in [Last], so a state which
+/// starts and ends within the same frame is not counted. Times are measured
+/// with [Time<Real>], so they are not affected by pausing or time scaling.
+///
+/// Useful for profiling and in tests:
+///
+/// ```rust,ignore
+/// app.add_systems(on_screen_ready::<WorldScreen>(), |metrics: Res<ScreenMetrics>| {
+///     assert!(metrics.loaded_within::<WorldScreen>(10));
+/// });
+/// ```
+#[derive(Resource, Debug, Default, Clone)]
+pub struct ScreenMetrics {
+    frame: u64,
+    screens: HashMap<ScreenId, ScreenStats>,
+    /// The IDs of the typed screens sampled so far.
+    typed: TypeIdMap<ScreenId>,
+}
+impl ScreenMetrics {
+    /// The statistics for the screen `S`, if it has changed state yet.
+    pub fn get<S: Screen>(&self) -> Option<&ScreenStats> {
+        self.typed
+            .get(&TypeId::of::<S>())
+            .and_then(|id| self.get_by_id(*id))
+    }
+    /// The statistics for the given screen, if it has changed state yet.
+    pub fn get_by_id(&self, id: ScreenId) -> Option<&ScreenStats> {
+        self.screens.get(&id)
+    }
+    /// Iterates over the statistics of every screen.
+    pub fn iter(&self) -> impl Iterator<Item = (ScreenId, &ScreenStats)> {
+        self.screens.iter().map(|(id, stats)| (*id, stats))
+    }
+    /// The number of frames sampled so far.
+    pub fn frame(&self) -> u64 {
+        self.frame
+    }
+    /// The number of state changes across all screens.
+    pub fn total_transitions(&self) -> u32 {
+        self.screens.values().map(|stats| stats.transitions).sum()
+    }
+    /// Did the screen `S` last finish loading in at most `frames` frames?
+    /// False if it has not finished loading yet.
+    pub fn loaded_within<S: Screen>(&self, frames: u64) -> bool {
+        self.get::<S>()
+            .and_then(|stats| stats.last_load)
+            .is_some_and(|timing| timing.frames <= frames)
+    }
+    /// Did the screen `S` last finish unloading in at most `frames` frames?
+    /// False if it has not finished unloading yet.
+    pub fn unloaded_within<S: Screen>(&self, frames: u64) -> bool {
+        self.get::<S>()
+            .and_then(|stats| stats.last_unload)
+            .is_some_and(|timing| timing.frames <= frames)
+    }
+
+    pub(crate) fn sample(&mut self, info: &ScreenInfo, now: Duration) {
+        let frame = self.frame;
+        if let Some(type_id) = info.type_id() {
+            self.typed.insert(type_id, info.screen_id());
+        }
+        let stats = self.screens.entry(info.screen_id()).or_default();
+        if stats.state == info.state() {
+            return;
+        }
+        let timing = StateTiming {
+            duration: now.saturating_sub(stats.entered_at),
+            frames: frame - stats.entered_frame,
+        };
+        match stats.state {
+            ScreenState::Loading => {
+                stats.total_loading += timing.duration;
+                stats.last_load = Some(timing);
+            }
+            ScreenState::Unloading => {
+                stats.total_unloading += timing.duration;
+                stats.last_unload = Some(timing);
+            }
+            _ => {}
+        }
+        match info.state() {
+            ScreenState::Loading => stats.loads += 1,
+            ScreenState::Failed(_) => stats.failures += 1,
+            _ => {}
+        }
+        stats.transitions += 1;
+        stats.state = info.state();
+        stats.entered_at = now;
+        stats.entered_frame = frame;
+    }
+
+    pub(crate) fn next_frame(&mut self) {
+        self.frame += 1;
+    }
+
+    pub(crate) fn record_cleanup(&mut self, id: ScreenId, despawned: usize) {
+        let stats = self.screens.entry(id).or_default();
+        stats.last_despawned = despawned;
+        stats.total_despawned += despawned;
+    }
+}
+
+/// The statistics for a single screen, see [ScreenMetrics].
+#[derive(Debug, Default, Clone)]
+pub struct ScreenStats {
+    /// The number of state changes.
+    pub transitions: u32,
+    /// The number of times the screen started loading.
+    pub loads: u32,
+    /// The number of times the screen failed to load.
+    pub failures: u32,
+    /// How long the screen spent in [ScreenState::Loading] the last time it
+    /// loaded.
+    pub last_load: Option<StateTiming>,
+    /// The total time spent in [ScreenState::Loading].
+    pub total_loading: Duration,
+    /// How long the screen spent in [ScreenState::Unloading] the last time it
+    /// unloaded.
+    pub last_unload: Option<StateTiming>,
+    /// The total time spent in [ScreenState::Unloading].
+    pub total_unloading: Duration,
+    /// The number of entities despawned at the last cleanup.
+    pub last_despawned: usize,
+    /// The total number of entities despawned at cleanup.
+    pub total_despawned: usize,
+    state: ScreenState,
+    entered_at: Duration,
+    entered_frame: u64,
+}
+impl ScreenStats {
+    /// The state the screen was in when last sampled.
+    pub fn state(&self) -> &ScreenState {
+        &self.state
+    }
+}
+
+/// How long a screen spent in a state.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct StateTiming {
+    /// The time spent, measured with [Time<Real>].
+    pub duration: Duration,
+    /// The number of frames spent.
+    pub frames: u64,
+}
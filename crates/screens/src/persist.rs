@@ -0,0 +1,142 @@
+use std::path::{Path, PathBuf};
+
+pub use crate::prelude::*;
+use bevy::reflect::{TypeRegistry, serde::TypedReflectSerializer};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Saves the [CurrentScreen] and its params to a file whenever it changes and
+/// when the app exits. With [Self::with_resume], the saved screen is switched
+/// to on startup instead of the [InitialScreen]. Meant for dev loops, where
+/// the app is restarted constantly.
+///
+/// If the saved screen is no longer registered, or the file is missing or
+/// broken, the [InitialScreen] is used as usual. If only the params can't be
+/// restored, the screen is switched to with its default params.
+#[derive(Debug, Clone)]
+pub struct ScreenPersistencePlugin {
+    /// The file the screen is saved to.
+    pub path: PathBuf,
+    /// Should the saved screen be restored on startup?
+    pub resume: bool,
+}
+impl Default for ScreenPersistencePlugin {
+    fn default() -> Self {
+        Self::new("last_screen.ron")
+    }
+}
+impl ScreenPersistencePlugin {
+    /// Saves the screen to the given file, without restoring it.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            resume: false,
+        }
+    }
+    /// Restores the saved screen on startup, see [ScreenPersistencePlugin].
+    pub fn with_resume(mut self, resume: bool) -> Self {
+        self.resume = resume;
+        self
+    }
+}
+impl Plugin for ScreenPersistencePlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(ScreenPersistence {
+            path: self.path.clone(),
+            resume: self.resume,
+        });
+        app.add_systems(
+            Last,
+            save_current_screen.run_if(resource_changed::<CurrentScreen>.or(on_message::<AppExit>)),
+        );
+    }
+}
+
+/// Where the [CurrentScreen] is saved, and whether it is restored. Added by
+/// the [ScreenPersistencePlugin].
+#[derive(Resource, Debug, Clone)]
+pub struct ScreenPersistence {
+    #[allow(missing_docs)]
+    pub path: PathBuf,
+    #[allow(missing_docs)]
+    pub resume: bool,
+}
+impl ScreenPersistence {
+    /// Reads the saved screen. Returns `None` if nothing has been saved yet.
+    pub fn load(&self) -> Result<Option<PersistedScreen>, ScreenPersistenceError> {
+        match std::fs::read_to_string(&self.path) {
+            Ok(contents) => Ok(Some(PersistedScreen::from_ron(&contents)?)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+    /// Writes the screen to [Self::path].
+    pub fn save(&self, screen: &PersistedScreen) -> Result<(), ScreenPersistenceError> {
+        if let Some(dir) = self.path.parent().filter(|dir| dir != &Path::new("")) {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(&self.path, screen.to_ron()?)?;
+        Ok(())
+    }
+}
+
+/// A screen as saved by the [ScreenPersistencePlugin].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PersistedScreen {
+    /// The screen's [Screen::name].
+    pub name: String,
+    /// The screen's [Screen::Params] as RON, see [ScreenInfo::parse_params].
+    pub params: Option<String>,
+}
+impl PersistedScreen {
+    /// Captures the screen and its params. The params are skipped if they
+    /// can't be serialized.
+    pub fn new(info: &ScreenInfo, registry: &TypeRegistry) -> Self {
+        let serializer = TypedReflectSerializer::new(info.params().as_partial_reflect(), registry);
+        let params = ron::to_string(&serializer)
+            .inspect_err(|e| warn!("Could not save the params of {}: {e}", info.name()))
+            .ok();
+        Self {
+            name: info.name().to_owned(),
+            params,
+        }
+    }
+    #[allow(missing_docs)]
+    pub fn from_ron(ron: &str) -> Result<Self, ScreenPersistenceError> {
+        Ok(ron::from_str(ron)?)
+    }
+    #[allow(missing_docs)]
+    pub fn to_ron(&self) -> Result<String, ScreenPersistenceError> {
+        Ok(ron::ser::to_string_pretty(self, default())?)
+    }
+    /// Builds the message which switches back to this screen. Returns `None`
+    /// if the screen is no longer registered. Params which no longer fit the
+    /// screen are replaced by its defaults.
+    pub fn to_switch_msg(
+        &self,
+        screens: &Screens,
+        registry: &TypeRegistry,
+    ) -> Option<SwitchToScreenMsg> {
+        let info = screens.get_by_name(&self.name).ok()?;
+        let mut msg = SwitchToScreenMsg::new(info.screen_id());
+        if let Some(params) = &self.params {
+            match info.parse_params(registry, params) {
+                Ok(params) => msg = msg.with_params(params),
+                Err(e) => warn!("Could not restore the params of {}: {e}", self.name),
+            }
+        }
+        Some(msg)
+    }
+}
+
+/// Why a [PersistedScreen] could not be read or written.
+#[allow(missing_docs)]
+#[derive(Error, Debug)]
+pub enum ScreenPersistenceError {
+    #[error("Could not access the saved screen: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Could not parse the saved screen: {0}")]
+    Parse(#[from] ron::error::SpannedError),
+    #[error("Could not serialize the screen: {0}")]
+    Serialize(#[from] ron::Error),
+}
@@ -8,15 +8,32 @@ use crate::prelude::*;
 pub struct ScreenPlugin;
 impl Plugin for ScreenPlugin {
     fn build(&self, app: &mut App) {
+        app.init_resource::<ScreenIds>();
         app.init_resource::<ScreenRegistry>();
         app.init_resource::<ScreenData>();
         app.init_resource::<InitialScreen>();
+        app.init_resource::<FallbackScreen>();
         app.init_resource::<CurrentScreen>();
+        app.init_resource::<ScreenStack>();
+        app.init_resource::<ScreenHistory>();
+        app.init_resource::<ActiveTransition>();
+        app.init_resource::<ScreenMetrics>();
         app.add_message::<SwitchToScreenMsg>();
+        app.add_message::<ScreenStackMsg>();
         app.add_plugins((
             HierarchyPropagatePlugin::<Persistent>::new(PostUpdate),
             HierarchyPropagatePlugin::<ScreenScoped>::new(PostUpdate),
         ));
         app.add_plugins(super::systems::plugin);
     }
+
+    // In `finish` so that AssetPlugin can be added after this plugin.
+    fn finish(&self, app: &mut App) {
+        if app.is_plugin_added::<AssetPlugin>() {
+            app.init_asset::<ScreenFlow>();
+            app.register_asset_loader(ScreenFlowLoader);
+        } else {
+            warn!("AssetPlugin was not added, so ScreenFlow assets can't be loaded");
+        }
+    }
 }
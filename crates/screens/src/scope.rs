@@ -1,31 +1,267 @@
-use std::any::TypeId;
+use std::{any::TypeId, sync::Arc};
 
 pub use crate::prelude::*;
 use bevy::{
-    ecs::system::{ScheduleSystem, SystemIdMarker},
+    asset::AssetPath,
+    ecs::{
+        schedule::ScheduleLabel,
+        system::{
+            BoxedSystem, IntoObserverSystem, ScheduleSystem, SystemChangeTick, SystemIdMarker,
+        },
+    },
     platform::collections::HashMap,
 };
 use strum::IntoEnumIterator;
 
+/// Registers screens with an [App], or with a [World] after startup, e.g. from
+/// a dylib hot-reload or a mod loader. Use [Commands::queue] to register
+/// screens from a system.
+///
+/// Screens registered through the [World] are not mirrored into
+/// [ScreenStateOf], unless they were registered through the [App] before.
 #[allow(missing_docs)]
 pub trait RegisterScreen {
     /// Registers a [Screen] to the application.
     fn register_screen<S: Screen>(&mut self) -> &mut Self;
+    /// Registers a screen which has no [Screen] type, e.g. one defined by a
+    /// tool, mod or data file. The screen is identified by its name, which
+    /// must be unique. Look up its [ScreenId] with [Screens::get_by_name] and
+    /// switch to it with [SwitchToScreenMsg].
+    ///
+    /// Typed APIs such as [SwitchToScreen] or [ScreenInfoRef] are not
+    /// available for dynamic screens, and they take no [Screen::Params].
+    fn register_dynamic_screen(
+        &mut self,
+        name: impl Into<String>,
+        builder: impl FnOnce(ScreenScopeBuilder<DynamicScreen>) -> ScreenScopeBuilder<DynamicScreen>,
+    ) -> &mut Self;
+    /// Unregisters a [Screen]. If the screen is loaded, its OnUnload and
+    /// OnUnloaded schedules run, though its Unloading schedule does not. Its
+    /// scope is cleaned up, its schedules are removed, including systems added
+    /// to them outside of the [ScreenScopeBuilder], and its observers are
+    /// despawned. The screen is removed from the [ScreenStack] and
+    /// [ScreenHistory]. If it was on top of the stack, the screen beneath it
+    /// is uncovered and becomes the [CurrentScreen], as with [PopScreen].
+    fn unregister_screen<S: Screen>(&mut self) -> &mut Self;
+    /// Unregisters the dynamic screen with the given name. See
+    /// [Self::unregister_screen].
+    fn unregister_dynamic_screen(&mut self, name: &str) -> &mut Self;
+    /// Replaces a registered [Screen] with a new version of it, e.g. after
+    /// hot-reloading its systems. The screen keeps its [ScreenId]. If it is
+    /// the [CurrentScreen] or on the [ScreenStack], it is reloaded with its
+    /// current [Screen::Params] so the new systems take effect.
+    fn reregister_screen<S: Screen>(&mut self) -> &mut Self;
+    /// Replaces the dynamic screen with the given name, or registers it if
+    /// it does not exist. See [Self::reregister_screen].
+    fn reregister_dynamic_screen(
+        &mut self,
+        name: impl Into<String>,
+        builder: impl FnOnce(ScreenScopeBuilder<DynamicScreen>) -> ScreenScopeBuilder<DynamicScreen>,
+    ) -> &mut Self;
+}
+impl RegisterScreen for World {
+    fn register_screen<S: Screen>(&mut self) -> &mut Self {
+        let id = self
+            .get_resource_or_init::<ScreenIds>()
+            .of(TypeId::of::<S>());
+        S::builder(ScreenScopeBuilder::<S>::new(id, None)).build(self);
+        self
+    }
+    fn register_dynamic_screen(
+        &mut self,
+        name: impl Into<String>,
+        builder: impl FnOnce(ScreenScopeBuilder<DynamicScreen>) -> ScreenScopeBuilder<DynamicScreen>,
+    ) -> &mut Self {
+        let id = self.get_resource_or_init::<ScreenIds>().next();
+        builder(ScreenScopeBuilder::new(id, Some(name.into()))).build(self);
+        self
+    }
+    fn unregister_screen<S: Screen>(&mut self) -> &mut Self {
+        if let Some(id) = find_typed::<S>(self)
+            && let Some(info) = unregister(self, id)
+        {
+            forget(self, info.screen_id());
+        }
+        self
+    }
+    fn unregister_dynamic_screen(&mut self, name: &str) -> &mut Self {
+        if let Some(id) = find_dynamic(self, name)
+            && let Some(info) = unregister(self, id)
+        {
+            forget(self, info.screen_id());
+        }
+        self
+    }
+    fn reregister_screen<S: Screen>(&mut self) -> &mut Self {
+        let old = find_typed::<S>(self).and_then(|id| unregister(self, id));
+        self.register_screen::<S>();
+        if let Some(old) = old {
+            reload(self, old);
+        }
+        self
+    }
+    fn reregister_dynamic_screen(
+        &mut self,
+        name: impl Into<String>,
+        builder: impl FnOnce(ScreenScopeBuilder<DynamicScreen>) -> ScreenScopeBuilder<DynamicScreen>,
+    ) -> &mut Self {
+        let name = name.into();
+        let id = find_dynamic(self, &name);
+        let old = id.and_then(|id| unregister(self, id));
+        let id = id.unwrap_or_else(|| self.get_resource_or_init::<ScreenIds>().next());
+        builder(ScreenScopeBuilder::new(id, Some(name))).build(self);
+        if let Some(old) = old {
+            reload(self, old);
+        }
+        self
+    }
 }
 impl RegisterScreen for App {
     fn register_screen<S: Screen>(&mut self) -> &mut Self {
-        S::builder(ScreenScopeBuilder::<S>::default()).build(self);
+        self.world_mut().register_screen::<S>();
+        if self.is_plugin_added::<ScreenStatesPlugin>() {
+            add_state_mirror::<S>(self);
+        }
+        self
+    }
+    fn register_dynamic_screen(
+        &mut self,
+        name: impl Into<String>,
+        builder: impl FnOnce(ScreenScopeBuilder<DynamicScreen>) -> ScreenScopeBuilder<DynamicScreen>,
+    ) -> &mut Self {
+        self.world_mut().register_dynamic_screen(name, builder);
+        self
+    }
+    fn unregister_screen<S: Screen>(&mut self) -> &mut Self {
+        self.world_mut().unregister_screen::<S>();
+        self
+    }
+    fn unregister_dynamic_screen(&mut self, name: &str) -> &mut Self {
+        self.world_mut().unregister_dynamic_screen(name);
         self
     }
+    fn reregister_screen<S: Screen>(&mut self) -> &mut Self {
+        self.world_mut().reregister_screen::<S>();
+        if self.is_plugin_added::<ScreenStatesPlugin>() {
+            add_state_mirror::<S>(self);
+        }
+        self
+    }
+    fn reregister_dynamic_screen(
+        &mut self,
+        name: impl Into<String>,
+        builder: impl FnOnce(ScreenScopeBuilder<DynamicScreen>) -> ScreenScopeBuilder<DynamicScreen>,
+    ) -> &mut Self {
+        self.world_mut().reregister_dynamic_screen(name, builder);
+        self
+    }
+}
+
+fn find_typed<S: Screen>(world: &World) -> Option<ScreenId> {
+    world
+        .get_resource::<ScreenRegistry>()?
+        .get(&TypeId::of::<S>())
+        .ok()
+}
+
+fn find_dynamic(world: &World, name: &str) -> Option<ScreenId> {
+    world
+        .get_resource::<ScreenData>()?
+        .iter_some()
+        .find(|info| info.type_id().is_none() && info.name() == name)
+        .map(|info| info.screen_id())
+}
+
+/// Cleans up the screen's scope and tears down everything it registered.
+fn unregister(world: &mut World, id: ScreenId) -> Option<ScreenInfo> {
+    if world
+        .get_resource::<ScreenData>()
+        .is_none_or(|data| data.get(id).is_err())
+    {
+        warn!("{:?} is not registered, not unregistering", id);
+        return None;
+    }
+    let (key, state) = world
+        .resource::<ScreenData>()
+        .get(id)
+        .map(|info| (info.key(), info.state().clone()))
+        .ok()?;
+    if matches!(
+        state,
+        ScreenState::Loading | ScreenState::Ready | ScreenState::Paused
+    ) {
+        let _ = world.try_run_schedule(OnScreenUnload(key));
+    }
+    let _ = world.run_system_cached_with(clean_up_scoped_resources, id);
+    let _ = world.run_system_cached_with(clean_up_scoped_entities, id);
+    if matches!(
+        state,
+        ScreenState::Loading
+            | ScreenState::Ready
+            | ScreenState::Paused
+            | ScreenState::Unloading
+            | ScreenState::Cleanup
+    ) {
+        let _ = world.try_run_schedule(OnScreenUnloaded(key));
+    }
+
+    let info = world.resource_mut::<ScreenData>().remove(id)?;
+    if let Some(type_id) = info.type_id() {
+        world.resource_mut::<ScreenRegistry>().remove(&type_id);
+    }
+    if let Some(guard) = info.exit_guard() {
+        let _ = world.unregister_system(guard);
+    }
+    for observer in info.registered_observers.iter() {
+        world.despawn(*observer);
+    }
+    let mut schedules = world.resource_mut::<Schedules>();
+    for kind in ScreenSchedule::iter() {
+        schedules.remove(ScreenScheduleLabel::from_key(kind, key));
+    }
+    schedules.remove(OnScreenLoadQueued(key));
+    schedules.remove(OnScreenLoad(key));
+    schedules.remove(OnScreenReady(key));
+    schedules.remove(OnScreenUnload(key));
+    schedules.remove(OnScreenCleanup(key));
+    schedules.remove(OnScreenUnloaded(key));
+    schedules.remove(OnScreenFailed(key));
+    debug!("Unregistered {} (id={:?})", info.name(), id);
+    Some(info)
+}
+
+/// Removes every reference to an unregistered screen.
+fn forget(world: &mut World, id: ScreenId) {
+    let _ = world.run_system_cached_with(forget_screen, id);
+}
+
+/// Reloads a re-registered screen if it is in use, keeping its params.
+fn reload(world: &mut World, old: ScreenInfo) {
+    let id = old.screen_id();
+    let stack = world.resource::<ScreenStack>();
+    let covered = stack.is_covered(id);
+    if world.resource::<CurrentScreen>().get_id() != Some(id) && !stack.contains(&id) {
+        return;
+    }
+    let tick = world.change_tick();
+    let mut data = world.resource_mut::<ScreenData>();
+    let info = r!(data.get_mut(id));
+    if let Err(e) = info.set_params(Some(old.params().as_partial_reflect())) {
+        warn!("Could not keep the params of {}: {e}", info.name());
+    }
+    info.set_covered(covered);
+    info.load(tick);
 }
 
 /// The [ScreenScopeBuilder] is the main entrypoint for screen registration.
 /// Use it to add scoped systems to your screen. These scoped systems will only run
 /// when the screen is in the [ScreenState] analgous to the specified [ScreenSchedule].
 ///
-/// When a screen is unloaded, it will clean up all entities marked as non-[Persistent] entities.
-/// Entities can be marked as [ScreenScoped] to opt out of persistence. This is primarily useful
-/// when propagating entity persistence, using [Propagate(Persistence).](bevy::app::Propagate)
+/// When a screen is unloaded, it will clean up all non-[Persistent] entities it
+/// owns. Entities are owned by the screen whose schedules spawned them, see
+/// [ScreenOwner]. Entities can be marked as [ScreenScoped] to opt out of
+/// persistence. This is primarily useful when propagating entity persistence,
+/// using [Propagate(Persistence).](bevy::app::Propagate)
 ///
 /// Be aware that loading is _disabled by default,_ unless you specify
 /// a system to run in [ScreenSchedule::Loading], or you
@@ -33,14 +269,36 @@ impl RegisterScreen for App {
 ///
 /// If you want to allow the screen to run its [Update] schedule while it is in
 /// [ScreenState::Loading], set [Self::with_load_strategy] to [LoadStrategy::Nonblocking].
+///
+/// If you want to stop the screen's [Update] schedule while another screen is
+/// pushed on top of it, set [Self::with_covered_policy].
+///
+/// If you want to play a [ScreenTransition] when switching to this screen, set
+/// [Self::with_transition].
+///
+/// If you want the screen to finish loading once its assets have loaded, use
+/// [Self::load_assets] or [ScreenInfoMut::track_assets].
+///
+/// If you want to show another screen while this one loads, set
+/// [Self::with_loading_screen].
 pub struct ScreenScopeBuilder<S>
 where
     S: Screen,
 {
+    id: ScreenId,
+    /// Set for dynamic screens, which have no [Screen::name].
+    name: Option<String>,
     schedules: HashMap<ScreenSchedule, Schedule>,
     skip_load: Option<bool>,
     skip_unload: Option<bool>,
+    skip_history: bool,
     load_strategy: LoadStrategy,
+    covered_policy: CoveredPolicy,
+    transition: Option<Arc<dyn ScreenTransition>>,
+    loading_screen: Option<ScreenKey>,
+    exit_guard: Option<BoxedSystem<(), ExitDecision>>,
+    resources: Vec<ScopedResource>,
+    observers: Vec<ScopedObserver>,
     _ghost: PhantomData<S>,
 }
 
@@ -60,11 +318,150 @@ where
         self.skip_unload = Some(val);
         self
     }
+    /// Do not record this screen in the [ScreenHistory], e.g. for splash or
+    /// loading screens. By default this is false.
+    pub fn with_skip_history(&mut self, val: bool) -> &mut Self {
+        self.skip_history = val;
+        self
+    }
     /// Sets the [LoadStrategy]. By default, this is Blocking.
     pub fn with_load_strategy(&mut self, val: LoadStrategy) -> &mut Self {
         self.load_strategy = val;
         self
     }
+    /// Sets the [CoveredPolicy], i.e. what this screen does while another
+    /// screen is pushed on top of it. By default, this is Run.
+    pub fn with_covered_policy(&mut self, val: CoveredPolicy) -> &mut Self {
+        self.covered_policy = val;
+        self
+    }
+    /// Sets the [ScreenTransition] played when switching to this screen. By
+    /// default, there is no transition.
+    pub fn with_transition(&mut self, val: impl ScreenTransition) -> &mut Self {
+        self.transition = Some(Arc::new(val));
+        self
+    }
+    /// Sets a system which is asked before switching away from this screen,
+    /// e.g. to protect unsaved changes. If it returns [ExitDecision::Deny],
+    /// the switch is cancelled and [ScreenSwitchDenied] is triggered. If it
+    /// returns [ExitDecision::Defer], it is asked again next frame.
+    ///
+    /// Forced switches skip the guard, see [SwitchToScreen::forced]. Pushing
+    /// and popping screens is not guarded.
+    pub fn with_exit_guard<M>(
+        &mut self,
+        system: impl IntoSystem<(), ExitDecision, M> + 'static,
+    ) -> &mut Self {
+        self.exit_guard = Some(Box::new(IntoSystem::into_system(system)));
+        self
+    }
+    /// Shows the screen `L` while this screen is [ScreenState::LoadQueued] or
+    /// [ScreenState::Loading]. `L` is unloaded once this screen leaves those
+    /// states, e.g. when it becomes Ready. `L` must be registered.
+    ///
+    /// This is useful for screens with [LoadStrategy::Blocking], which would
+    /// otherwise show nothing while they load.
+    pub fn with_loading_screen<L: Screen>(&mut self) -> &mut Self {
+        self.loading_screen = Some(ScreenKey::of::<L>());
+        self
+    }
+    /// Like [Self::with_loading_screen], but takes the loading screen's
+    /// [ScreenId], e.g. for dynamic loading screens.
+    pub fn with_loading_screen_id(&mut self, id: ScreenId) -> &mut Self {
+        self.loading_screen = Some(ScreenKey::Id(id));
+        self
+    }
+
+    /// Loads the [AssetCollection](bevy_asset_loader::asset_collection::AssetCollection)
+    /// whenever the screen starts loading. Once every asset in the collection
+    /// has loaded, the collection is inserted as a resource and the screen
+    /// becomes Ready. Progress can be read with [ScreenInfo::load_progress].
+    ///
+    /// This enables loading for the screen, unless [Self::with_skip_load] is
+    /// set.
+    #[cfg(feature = "bevy_asset_loader")]
+    pub fn load_assets<C: bevy_asset_loader::asset_collection::AssetCollection>(
+        &mut self,
+    ) -> &mut Self {
+        self.skip_load.get_or_insert(false);
+        let id = self.id;
+        self.add_systems(ScreenSchedule::OnLoad, move |world: &mut World| {
+            track_collection::<C>(world, id)
+        })
+    }
+
+    /// Loads the [DynamicScene] at `path` whenever the screen starts loading,
+    /// and spawns it once the screen is Ready. The scene is spawned beneath a
+    /// [ScreenSceneRoot] owned by the screen, so the whole scene is despawned
+    /// when the screen is cleaned up. The screen waits for the scene to load
+    /// like any other tracked asset, see [ScreenInfo::load_progress].
+    ///
+    /// This enables loading for the screen, unless [Self::with_skip_load] is
+    /// set, in which case the scene is loaded when the screen becomes Ready.
+    /// Requires the [ScenePlugin](bevy::scene::ScenePlugin).
+    pub fn with_scene(&mut self, path: impl Into<AssetPath<'static>>) -> &mut Self {
+        self.skip_load.get_or_insert(false);
+        let path = path.into();
+        let id = self.id;
+        let load_path = path.clone();
+        self.add_systems(
+            ScreenSchedule::OnLoad,
+            (move || (id, load_path.clone())).pipe(load_scene),
+        );
+        self.add_systems(
+            ScreenSchedule::OnReady,
+            (move || (id, path.clone())).pipe(spawn_scene),
+        )
+    }
+
+    /// Scopes the resource to this screen. It is initialized when the screen
+    /// starts loading, or when it becomes Ready if loading is skipped, and is
+    /// removed when the screen is cleaned up. Access it with [ScreenRes] to
+    /// get a clear error while the screen is unloaded.
+    pub fn init_resource<R: Resource + FromWorld>(&mut self) -> &mut Self {
+        self.add_scoped_resource::<R>(Arc::new(|world: &mut World| {
+            let resource = R::from_world(world);
+            world.insert_resource(resource);
+        }))
+    }
+    /// Scopes a clone of the resource to this screen. See [Self::init_resource].
+    pub fn insert_resource<R: Resource + Clone>(&mut self, resource: R) -> &mut Self {
+        self.add_scoped_resource::<R>(Arc::new(move |world: &mut World| {
+            world.insert_resource(resource.clone());
+        }))
+    }
+    fn add_scoped_resource<R: Resource>(
+        &mut self,
+        insert: Arc<dyn Fn(&mut World) + Send + Sync>,
+    ) -> &mut Self {
+        self.resources.push(ScopedResource {
+            name: std::any::type_name::<R>(),
+            insert,
+            remove: |world| {
+                world.remove_resource::<R>();
+            },
+        });
+        self
+    }
+
+    /// Adds an observer which is only active while this screen is loaded. The
+    /// observer is spawned when the screen starts loading, or when it becomes
+    /// Ready if loading is skipped, and is despawned when the screen is
+    /// cleaned up. See [ScreenObserver].
+    pub fn add_observer<E: Event, B: Bundle, M: 'static>(
+        &mut self,
+        observer: impl IntoObserverSystem<E, B, M> + Clone + Sync,
+    ) -> &mut Self {
+        self.observers.push(ScopedObserver {
+            spawn: Arc::new(move |world: &mut World| {
+                world
+                    .add_observer(observer.clone())
+                    .insert(ScreenObserver)
+                    .id()
+            }),
+        });
+        self
+    }
 
     /// Add systems to the schedule scope. Will run in the specified schedule.
     ///
@@ -74,6 +471,7 @@ where
     /// - FixedUpdate
     /// - Loading
     /// - Unloading
+    /// - Paused
     ///
     /// ... While the following run only on screen state transitions:
     ///
@@ -81,6 +479,7 @@ where
     /// - OnReady
     /// - OnUnload
     /// - OnUnloaded
+    /// - OnFailed
     ///
     /// Note that adding an `On` system _will not_ automatically enable loading
     /// or unloading for this screen. Make sure you either have systems in the
@@ -92,28 +491,39 @@ where
         kind: ScreenSchedule,
         systems: impl IntoScheduleConfigs<ScheduleSystem, M>,
     ) -> &mut Self {
+        let key = self.key();
         self.schedules
             .entry(kind)
-            .or_insert(Schedule::new(ScreenScheduleLabel::new::<S>(kind)))
+            .or_insert(Schedule::new(ScreenScheduleLabel::from_key(kind, key)))
             .add_systems(systems);
         self
     }
 
-    fn build(self, app: &mut App) {
-        if let Some(registry) = app.world().get_resource::<ScreenRegistry>()
-            && registry.get(&TypeId::of::<S>()).is_ok()
+    /// The key of the screen's schedules, see [ScreenKey].
+    fn key(&self) -> ScreenKey {
+        match self.name {
+            Some(_) => ScreenKey::Id(self.id),
+            None => ScreenKey::of::<S>(),
+        }
+    }
+
+    fn build(self, world: &mut World) {
+        let id = self.id;
+        let key = self.key();
+        let dynamic = self.name.is_some();
+        let name = self.name.unwrap_or_else(S::name);
+        if let Some(data) = world.get_resource::<ScreenData>()
+            && (data.get(id).is_ok() || dynamic && data.iter_some().any(|v| v.name() == name))
         {
-            warn!("Already registered {}, not registering again", S::name());
+            warn!("Already registered {}, not registering again", name);
             return;
         }
 
-        let id = {
-            let ids = app.world_mut().get_resource_or_init::<ScreenIds>();
-            ids.next()
-        };
-
-        let tick = app.world_mut().change_tick();
+        let tick = world.change_tick();
         let mut data = ScreenInfo::new::<S>(id, tick);
+        if dynamic {
+            data.set_dynamic(name.clone());
+        }
         let skip_load = self
             .schedules
             .get(&ScreenSchedule::Loading)
@@ -126,110 +536,224 @@ where
             .unwrap_or_default();
         data.set_skip_load(self.skip_load.unwrap_or(skip_load));
         data.set_skip_unload(self.skip_unload.unwrap_or(skip_unload));
+        data.set_skip_history(self.skip_history);
         data.set_load_strategy(self.load_strategy);
+        data.set_covered_policy(self.covered_policy);
+        data.set_transition(self.transition);
+        data.set_loading_screen(
+            self.loading_screen
+                .map(|key| world.get_resource_or_init::<ScreenIds>().resolve(key)),
+        );
+        data.set_scoped_resources(self.resources);
+        data.set_scoped_observers(self.observers);
+        data.set_exit_guard(
+            self.exit_guard
+                .map(|guard| world.register_boxed_system(guard)),
+        );
 
-        {
-            let mut data_res = app.world_mut().get_resource_or_init::<ScreenData>();
-            let min_size = id.min(data_res.len());
-            data_res.resize_with(min_size, || None);
-            data_res.insert(*id, Some(data));
-        };
-        {
-            let mut registry = app.world_mut().get_resource_or_init::<ScreenRegistry>();
-            registry.insert(TypeId::of::<S>(), id);
-        };
+        if !dynamic {
+            world
+                .get_resource_or_init::<ScreenRegistry>()
+                .insert(TypeId::of::<S>(), id);
+            world
+                .get_resource_or_init::<AppTypeRegistry>()
+                .write()
+                .register::<S::Params>();
+            data.state_mirror = Some(add_state_mirror::<S>);
 
-        // watch screen switcher
-        app.add_observer(on_switch_screen::<S>);
-        app.add_observer(on_finish_loading::<S>);
-        app.add_observer(on_finish_unloading::<S>);
-        app.add_systems(on_screen_cleanup::<S>(), clean_up_scoped_entities::<S>);
+            // watch screen switcher
+            data.registered_observers = vec![
+                world.add_observer(on_switch_screen::<S>).id(),
+                world.add_observer(on_push_screen::<S>).id(),
+                world.add_observer(on_finish_loading::<S>).id(),
+                world.add_observer(on_finish_unloading::<S>).id(),
+                world.add_observer(on_fail_loading::<S>).id(),
+                world.add_observer(on_preload_screen::<S>).id(),
+                world.add_observer(on_cancel_preload::<S>).id(),
+                world.add_observer(on_pause_screen::<S>).id(),
+                world.add_observer(on_resume_screen::<S>).id(),
+            ];
+        }
+        world.get_resource_or_init::<ScreenData>().insert(data);
+        let mut schedules = world.get_resource_or_init::<Schedules>();
+        schedules.add_systems(OnScreenCleanup(key), move |mut commands: Commands| {
+            commands.run_system_cached_with(clean_up_scoped_resources, id);
+            commands.run_system_cached_with(clean_up_scoped_entities, id);
+        });
 
         // scope systems
         for (kind, schedule) in self.schedules.into_iter() {
             let label = schedule.label();
+            let run = move |mut commands: Commands| commands.run_schedule(label);
             match kind {
                 ScreenSchedule::OnLoad => {
-                    app.add_systems(on_screen_load::<S>(), move |mut commands: Commands| {
-                        commands.run_schedule(label)
-                    });
+                    schedules.add_systems(OnScreenLoad(key), run);
                 }
                 ScreenSchedule::OnReady => {
-                    let label = schedule.label();
-                    app.add_systems(on_screen_ready::<S>(), move |mut commands: Commands| {
-                        commands.run_schedule(label)
-                    });
+                    schedules.add_systems(OnScreenReady(key), run);
                 }
                 ScreenSchedule::OnUnload => {
-                    let label = schedule.label();
-                    app.add_systems(on_screen_unload::<S>(), move |mut commands: Commands| {
-                        commands.run_schedule(label);
-                    });
+                    schedules.add_systems(OnScreenUnload(key), run);
                 }
                 ScreenSchedule::OnUnloaded => {
-                    let label = schedule.label();
-                    app.add_systems(on_screen_unloaded::<S>(), move |mut commands: Commands| {
-                        commands.run_schedule(label)
-                    });
+                    schedules.add_systems(OnScreenUnloaded(key), run);
+                }
+                ScreenSchedule::OnFailed => {
+                    schedules.add_systems(OnScreenFailed(key), run);
                 }
                 _ => {
                     // run on update, see [run_schedules](systems.rs)
                 }
             }
-            app.add_schedule(schedule);
+            schedules.insert(schedule);
         }
 
         // Lifecycle
         #[cfg(debug_assertions)]
         {
-            app.add_systems(on_screen_load_queued::<S>(), || {
-                debug!("LoadQueued {:?}", S::name())
-            });
-            app.add_systems(on_screen_load::<S>(), || {
-                debug!("   Loading {:?}", S::name())
-            });
-            app.add_systems(on_screen_ready::<S>(), || {
-                debug!("     Ready {:?}", S::name())
-            });
-            app.add_systems(on_screen_unload::<S>(), || {
-                debug!(" Unloading {:?}", S::name())
-            });
-            app.add_systems(on_screen_cleanup::<S>(), || {
-                debug!("   Cleanup {:?}", S::name())
-            });
-            app.add_systems(on_screen_unloaded::<S>(), || {
-                debug!("  Unloaded {:?}", S::name())
-            });
+            log_lifecycle(&mut schedules, OnScreenLoadQueued(key), "LoadQueued", &name);
+            log_lifecycle(&mut schedules, OnScreenLoad(key), "Loading", &name);
+            log_lifecycle(&mut schedules, OnScreenReady(key), "Ready", &name);
+            log_lifecycle(&mut schedules, OnScreenUnload(key), "Unloading", &name);
+            log_lifecycle(&mut schedules, OnScreenCleanup(key), "Cleanup", &name);
+            log_lifecycle(&mut schedules, OnScreenUnloaded(key), "Unloaded", &name);
+            log_lifecycle(&mut schedules, OnScreenFailed(key), "Failed", &name);
         }
-        debug!("Built {} (id={:?})", S::name(), id);
+        debug!("Built {} (id={:?})", name, id);
     }
 }
 
-impl<S> Default for ScreenScopeBuilder<S>
+impl<S> ScreenScopeBuilder<S>
 where
     S: Screen,
 {
-    fn default() -> Self {
+    fn new(id: ScreenId, name: Option<String>) -> Self {
+        let key = match name {
+            Some(_) => ScreenKey::Id(id),
+            None => ScreenKey::of::<S>(),
+        };
         let schedules = ScreenSchedule::iter()
-            .map(|kind| (kind, Schedule::new(ScreenScheduleLabel::new::<S>(kind))))
+            .map(|kind| {
+                (
+                    kind,
+                    Schedule::new(ScreenScheduleLabel::from_key(kind, key)),
+                )
+            })
             .collect::<HashMap<_, _>>();
         Self {
+            id,
+            name,
             schedules,
             skip_load: None,
             skip_unload: None,
+            skip_history: false,
             load_strategy: LoadStrategy::default(),
+            covered_policy: CoveredPolicy::default(),
+            transition: None,
+            loading_screen: None,
+            exit_guard: None,
+            resources: vec![],
+            observers: vec![],
             _ghost: PhantomData,
         }
     }
 }
 
-fn clean_up_scoped_entities<S: Screen>(
+#[cfg(debug_assertions)]
+fn log_lifecycle(
+    schedules: &mut Schedules,
+    label: impl ScheduleLabel,
+    state: &'static str,
+    name: &str,
+) {
+    let name = name.to_owned();
+    schedules.add_systems(
+        label,
+        move || debug!(screen = %name, state, "{:>10} {:?}", state, name),
+    );
+}
+
+fn load_scene(
+    In((id, path)): In<(ScreenId, AssetPath<'static>)>,
+    asset_server: Res<AssetServer>,
+    mut data: ResMut<ScreenData>,
+    mut commands: Commands,
+) {
+    let handle = asset_server.load::<DynamicScene>(path);
+    r!(data.get_mut(id)).add_asset_tracker(AssetTracker {
+        handles: vec![handle.clone().untyped()],
+        on_loaded: None,
+    });
+    commands.spawn((Name::new("Screen Scene"), ScreenSceneRoot(handle)));
+}
+
+fn spawn_scene(
+    In((id, path)): In<(ScreenId, AssetPath<'static>)>,
+    asset_server: Res<AssetServer>,
+    roots: Query<(Entity, &ScreenSceneRoot, &ScreenOwner), Without<DynamicSceneRoot>>,
+    mut commands: Commands,
+) {
+    let root = roots
+        .iter()
+        .find(|(_, root, owner)| owner.0 == id && root.path() == Some(&path));
+    match root {
+        Some((entity, root, _)) => {
+            commands
+                .entity(entity)
+                .insert(DynamicSceneRoot(root.0.clone()));
+        }
+        // Loading was skipped.
+        None => {
+            let handle = asset_server.load::<DynamicScene>(path);
+            commands.spawn((
+                Name::new("Screen Scene"),
+                ScreenSceneRoot(handle.clone()),
+                DynamicSceneRoot(handle),
+            ));
+        }
+    }
+}
+
+#[cfg(feature = "bevy_asset_loader")]
+fn track_collection<C: bevy_asset_loader::asset_collection::AssetCollection>(
+    world: &mut World,
+    id: ScreenId,
+) {
+    // Required by collections with dynamic assets.
+    world.init_resource::<bevy_asset_loader::dynamic_asset::DynamicAssets>();
+    let handles = C::load(world);
+    r!(world.resource_mut::<ScreenData>().get_mut(id)).add_asset_tracker(AssetTracker {
+        handles,
+        on_loaded: Some(insert_collection::<C>),
+    });
+}
+
+#[cfg(feature = "bevy_asset_loader")]
+fn insert_collection<C: bevy_asset_loader::asset_collection::AssetCollection>(world: &mut World) {
+    let collection = C::create(world);
+    world.insert_resource(collection);
+}
+
+fn clean_up_scoped_resources(In(id): In<ScreenId>, world: &mut World) {
+    let resources = r!(world.resource::<ScreenData>().get(id))
+        .scoped_resources()
+        .to_vec();
+    for resource in resources {
+        (resource.remove)(world);
+    }
+}
+
+fn clean_up_scoped_entities(
+    In(id): In<ScreenId>,
     mut commands: Commands,
-    mut screen_data: ScreenInfoMut<S>,
-    // Any entity which is (explicitly marked as ScreenScoped, or is _not_ marked
-    // as persistent) _and_ is not a top-level observer
+    mut screen_data: ResMut<ScreenData>,
+    mut metrics: ResMut<ScreenMetrics>,
+    tick: SystemChangeTick,
+    // Any entity owned by this screen which is (explicitly marked as
+    // ScreenScoped, or is _not_ marked as persistent) _and_ is not a top-level
+    // observer
     screen_scoped: Query<
-        Entity,
+        (Entity, &ScreenOwner),
         (
             Or<(
                 With<ScreenScoped>,  // is explicitly screen-scoped
@@ -242,17 +766,23 @@ fn clean_up_scoped_entities<S: Screen>(
         (
             Or<(With<Observer>, With<Window>, With<SystemIdMarker>)>, // there are probably others i'm missing
             Without<ChildOf>,
+            Without<ScreenObserver>,
         ),
     >,
 ) {
+    let mut despawned = 0;
     screen_scoped
         .iter()
+        .filter(|(_, owner)| ***owner == id)
+        .map(|(e, _)| e)
         .filter(|c| !top_levels.iter().contains(c))
         .for_each(|e| {
             if let Ok(mut cmds) = commands.get_entity(e) {
                 cmds.clear(); // removes all relationship components
                 cmds.despawn();
+                despawned += 1;
             }
         });
-    screen_data.finish_cleanup();
+    metrics.record_cleanup(id, despawned);
+    r!(screen_data.get_mut(id)).finish_cleanup(tick.this_run());
 }
@@ -0,0 +1,107 @@
+use std::hash::{Hash, Hasher};
+
+pub use crate::prelude::*;
+use bevy::state::{app::StatesPlugin, state::FreelyMutableState};
+
+/// Mirrors the [ScreenState] of every typed [Screen] into a Bevy [State],
+/// see [ScreenStateOf]. This lets plugins which use [in_state], [OnEnter] or
+/// [DespawnOnExit] work with screens directly.
+///
+/// Screens registered before or after this plugin is added are mirrored
+/// alike. Dynamic screens have no type, so they are not mirrored.
+#[derive(Default, Debug)]
+pub struct ScreenStatesPlugin;
+impl Plugin for ScreenStatesPlugin {
+    fn build(&self, app: &mut App) {
+        if !app.is_plugin_added::<StatesPlugin>() {
+            app.add_plugins(StatesPlugin);
+        }
+        // Mirror the screens which were registered before this plugin.
+        let mirrors = app
+            .world()
+            .get_resource::<ScreenData>()
+            .into_iter()
+            .flat_map(|data| data.iter_some().filter_map(|info| info.state_mirror))
+            .collect::<Vec<_>>();
+        for mirror in mirrors {
+            mirror(app);
+        }
+    }
+}
+
+/// The [ScreenState] of the screen `S`, as a Bevy [State]. Requires the
+/// [ScreenStatesPlugin].
+///
+/// The state is updated whenever the screen's [ScreenInfo::changed_at] tick
+/// changes, and the transition is applied in the next [StateTransition]. Do
+/// not set it with [NextState]; it is overwritten by the screen.
+///
+/// [SubStates] can use it as a source:
+///
+/// ```rust,ignore
+/// #[derive(SubStates, Default, Debug, Clone, PartialEq, Eq, Hash)]
+/// #[source(ScreenStateOf<WorldScreen> = ScreenStateOf { state: ScreenState::Ready, .. })]
+/// enum WorldPhase {
+///     #[default]
+///     Exploring,
+///     Fighting,
+/// }
+/// ```
+pub struct ScreenStateOf<S: Screen> {
+    #[allow(missing_docs)]
+    pub state: ScreenState,
+    _ghost: PhantomData<S>,
+}
+impl<S: Screen> ScreenStateOf<S> {
+    #[allow(missing_docs)]
+    pub fn new(state: ScreenState) -> Self {
+        Self {
+            state,
+            _ghost: PhantomData,
+        }
+    }
+    #[allow(missing_docs)]
+    pub fn loading() -> Self {
+        Self::new(ScreenState::Loading)
+    }
+    #[allow(missing_docs)]
+    pub fn ready() -> Self {
+        Self::new(ScreenState::Ready)
+    }
+    #[allow(missing_docs)]
+    pub fn unloading() -> Self {
+        Self::new(ScreenState::Unloading)
+    }
+    #[allow(missing_docs)]
+    pub fn unloaded() -> Self {
+        Self::new(ScreenState::Unloaded)
+    }
+}
+impl<S: Screen> Default for ScreenStateOf<S> {
+    fn default() -> Self {
+        Self::unloaded()
+    }
+}
+impl<S: Screen> Clone for ScreenStateOf<S> {
+    fn clone(&self) -> Self {
+        Self::new(self.state.clone())
+    }
+}
+impl<S: Screen> PartialEq for ScreenStateOf<S> {
+    fn eq(&self, other: &Self) -> bool {
+        self.state == other.state
+    }
+}
+impl<S: Screen> Eq for ScreenStateOf<S> {}
+impl<S: Screen> Hash for ScreenStateOf<S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.state.hash(state);
+    }
+}
+impl<S: Screen> std::fmt::Debug for ScreenStateOf<S> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ScreenStateOf<{}>({:?})", S::name(), self.state)
+    }
+}
+impl<S: Screen> States for ScreenStateOf<S> {}
+impl<S: Screen> FreelyMutableState for ScreenStateOf<S> {}
@@ -3,55 +3,350 @@
 use std::any::TypeId;
 
 use crate::prelude::*;
-use bevy::ecs::system::SystemChangeTick;
+use bevy::{
+    ecs::{
+        change_detection::Tick,
+        error::{HandleError, warn},
+        query::SpawnDetails,
+        schedule::ScheduleLabel,
+        system::{SystemChangeTick, SystemState},
+    },
+    scene::SceneInstanceReady,
+};
 
 fn handle_switch_msg(
-    mut reader: MessageReader<SwitchToScreenMsg>,
+    world: &mut World,
+    state: &mut SystemState<(
+        MessageReader<SwitchToScreenMsg>,
+        Res<ScreenData>,
+        Res<CurrentScreen>,
+        Option<Res<ScreenFlow>>,
+    )>,
+    // A switch deferred by an exit guard.
+    mut pending: Local<Option<SwitchToScreenMsg>>,
+) {
+    // get the most recent valid message. It replaces any deferred switch.
+    let (mut reader, registry, current_screen, flow) = state.get_mut(world);
+    let msg = reader
+        .read()
+        .collect_vec()
+        .into_iter()
+        .rev()
+        .find(|msg| {
+            registry.get(msg.id).is_ok_and(|data| {
+                data.check_params(msg.params.as_deref())
+                    .and_then(|_| match (&flow, current_screen.get_id()) {
+                        (Some(flow), Some(from)) if !msg.force => {
+                            flow.check(registry.get(from)?, data)
+                        }
+                        _ => Ok(()),
+                    })
+                    .inspect_err(|e| error!("{e}"))
+                    .is_ok()
+            })
+        })
+        .cloned();
+    if msg.is_some() {
+        *pending = msg;
+    }
+    let msg = rq!(pending.take());
+
+    // ask every screen which would be unloaded
+    if !msg.force {
+        let guards = world
+            .resource::<ScreenData>()
+            .iter_some()
+            .filter(|info| info.screen_id() != msg.id && !info.preloading())
+            .filter(|info| {
+                matches!(
+                    info.state(),
+                    ScreenState::Loading | ScreenState::Ready | ScreenState::Paused
+                )
+            })
+            .filter_map(|info| Some((info.screen_id(), info.exit_guard()?)))
+            .collect_vec();
+        let mut deferred = false;
+        for (id, guard) in guards {
+            let decision = world.run_system(guard).unwrap_or_else(|e| {
+                warn!("Could not run exit guard: {e}");
+                ExitDecision::Allow
+            });
+            match decision {
+                ExitDecision::Allow => {}
+                ExitDecision::Deny => {
+                    world.trigger(ScreenSwitchDenied {
+                        from: id,
+                        to: msg.id,
+                    });
+                    return;
+                }
+                ExitDecision::Defer => deferred = true,
+            }
+        }
+        if deferred {
+            *pending = Some(msg);
+            return;
+        }
+    }
+    r!(world.run_system_cached_with(switch_screen, msg));
+}
+
+/// Loads the given screen and unloads all others.
+fn switch_screen(
+    In(msg): In<SwitchToScreenMsg>,
     mut registry: ResMut<ScreenData>,
     tick: SystemChangeTick,
     mut commands: Commands,
     mut current_screen: ResMut<CurrentScreen>,
+    mut stack: ResMut<ScreenStack>,
+    mut transition: ResMut<ActiveTransition>,
 ) {
-    // get the most recent valid message, then load it and unload all others
-    if reader.is_empty() {
-        return;
-    }
-    let vec = reader.read().collect::<Vec<_>>();
-    let msg_key = vec.iter().rev().find(|msg| registry.get(msg.0).is_ok());
-    let msg_key = rq!(msg_key);
+    let id = msg.id;
+    r!(r!(registry.get_mut(id)).set_params(msg.params.as_deref()));
+    let has_outgoing = registry
+        .iter_some()
+        .any(|info| info.screen_id() != id && info.state() != ScreenState::Unloaded);
+    let default_transition = registry.get(id).ok().and_then(|info| info.transition());
+    let from = stack.iter().copied().filter(|from| *from != id).collect();
+    transition.play(
+        msg.transition.clone().or(default_transition.cloned()),
+        from,
+        id,
+        has_outgoing,
+    );
     for (key, value) in registry.iter_mut().enumerate() {
         if let Some(data) = value {
-            if key == *msg_key.0 {
+            data.set_covered(false);
+            if key == *id {
+                // A preloaded screen becomes active where it left off.
+                data.preloading = false;
                 data.queue_load(tick.this_run());
             } else {
                 data.unload(tick.this_run());
             }
         }
     }
+    stack.0 = vec![id];
     commands.trigger(ScreenChanged {
         from: **current_screen,
-        to: msg_key.0,
+        to: id,
     });
-    **current_screen = Some(msg_key.0)
+    **current_screen = Some(id)
+}
+
+fn handle_stack_msg(
+    mut reader: MessageReader<ScreenStackMsg>,
+    mut registry: ResMut<ScreenData>,
+    tick: SystemChangeTick,
+    mut commands: Commands,
+    mut current_screen: ResMut<CurrentScreen>,
+    mut stack: ResMut<ScreenStack>,
+    flow: Option<Res<ScreenFlow>>,
+) {
+    for msg in reader.read() {
+        let from = stack.top();
+        match *msg {
+            ScreenStackMsg::Push(id) => {
+                if stack.contains(&id) {
+                    warn!("{id:?} is already on the screen stack, not pushing again");
+                    continue;
+                }
+                if let (Some(flow), Some(from)) = (&flow, from)
+                    && let Err(e) = flow.check(c!(registry.get(from)), c!(registry.get(id)))
+                {
+                    error!("{e}");
+                    continue;
+                }
+                let info = c!(registry.get_mut(id));
+                info.preloading = false;
+                info.load(tick.this_run());
+                init_screen_scope(&mut commands, info);
+                if let Some(below) = from {
+                    c!(registry.get_mut(below)).set_covered(true);
+                }
+                stack.0.push(id);
+            }
+            ScreenStackMsg::Pop => {
+                if stack.len() <= 1 {
+                    warn!("Cannot pop the last screen on the screen stack");
+                    continue;
+                }
+                let top = c!(stack.0.pop());
+                c!(registry.get_mut(top)).unload(tick.this_run());
+                if let Some(below) = stack.top() {
+                    c!(registry.get_mut(below)).set_covered(false);
+                }
+            }
+        }
+        let to = c!(stack.top());
+        commands.trigger(ScreenChanged { from, to });
+        **current_screen = Some(to);
+    }
+}
+
+/// Inserts the screen's scoped resources and spawns its scoped observers once
+/// it has started loading. See [ScreenScopeBuilder::init_resource] and
+/// [ScreenScopeBuilder::add_observer].
+fn init_screen_scope(commands: &mut Commands, info: &mut ScreenInfo) {
+    if info.scope_initialized || !matches!(info.state(), ScreenState::Loading | ScreenState::Ready)
+    {
+        return;
+    }
+    for resource in info.scoped_resources() {
+        let insert = resource.insert.clone();
+        commands.queue(move |world: &mut World| insert(world));
+    }
+    let id = info.screen_id();
+    for observer in info.scoped_observers() {
+        let spawn = observer.spawn.clone();
+        commands.queue(move |world: &mut World| {
+            let entity = spawn(world);
+            world.entity_mut(entity).insert(ScreenOwner(id));
+        });
+    }
+    info.scope_initialized = true;
+}
+
+/// NOTE: This is registered in scope.rs
+pub(crate) fn on_push_screen<S: Screen>(
+    _trigger: On<PushScreen<S>>,
+    registry: Res<ScreenRegistry>,
+    mut commands: Commands,
+) {
+    let id = registry
+        .get(&TypeId::of::<S>())
+        .map_err(|_| ScreenError::NoSuchScreen(S::name()));
+    commands.write_message(ScreenStackMsg::Push(id.unwrap()));
+}
+
+fn on_pop_screen(_trigger: On<PopScreen>, mut commands: Commands) {
+    commands.write_message(ScreenStackMsg::Pop);
+}
+
+/// Removes every reference to an unregistered screen. If it was on top of the
+/// [ScreenStack], the screen beneath it is uncovered, as with [PopScreen].
+pub(crate) fn forget_screen(
+    In(id): In<ScreenId>,
+    mut registry: ResMut<ScreenData>,
+    mut current_screen: ResMut<CurrentScreen>,
+    mut stack: ResMut<ScreenStack>,
+    mut history: ResMut<ScreenHistory>,
+) {
+    let was_top = stack.top() == Some(id);
+    stack.0.retain(|v| *v != id);
+    history.remove(id);
+    if **current_screen == Some(id) {
+        **current_screen = stack.top();
+    }
+    if was_top && let Some(top) = stack.top() {
+        r!(registry.get_mut(top)).set_covered(false);
+    }
+}
+
+/// Records the screens switched to in the [ScreenHistory].
+fn record_history(
+    trigger: On<ScreenChanged>,
+    mut history: ResMut<ScreenHistory>,
+    stack: Res<ScreenStack>,
+    data: Res<ScreenData>,
+) {
+    let going_back = history.going_back.take();
+    // Pushed screens are not recorded.
+    if stack.len() > 1 {
+        return;
+    }
+    let info = r!(data.get(trigger.to));
+    if info.skip_history() {
+        return;
+    }
+    let params = info.params().to_dynamic();
+    // Going back rewinds the history to the entry which was switched to.
+    if let Some(index) = going_back
+        && history
+            .get(index)
+            .is_some_and(|entry| entry.id == trigger.to)
+    {
+        history.truncate(index);
+    }
+    match history.last_mut() {
+        // Switching to the same screen again, or popping back down to it.
+        Some(entry) if entry.id == trigger.to => entry.params = params,
+        _ => history.push(HistoryEntry {
+            id: trigger.to,
+            params,
+        }),
+    }
+}
+
+fn on_go_back(
+    trigger: On<GoBack>,
+    mut history: ResMut<ScreenHistory>,
+    current_screen: Res<CurrentScreen>,
+    mut commands: Commands,
+) {
+    let on_current = history
+        .last()
+        .is_some_and(|entry| Some(entry.id) == current_screen.get_id());
+    if history.len() <= usize::from(on_current) {
+        warn!("No previous screen to go back to");
+        return;
+    }
+    // The history is rewound in record_history, once the switch happens.
+    let index = history.len() - 1 - usize::from(on_current);
+    let entry = r!(history.get(index));
+    let mut msg = SwitchToScreenMsg::new(entry.id);
+    if !trigger.reset_params {
+        msg = msg.with_params(entry.params.to_dynamic());
+    }
+    msg.force = trigger.force;
+    history.going_back = Some(index);
+    commands.write_message(msg);
 }
 /// NOTE: This is registered in scope.rs
 pub(crate) fn on_switch_screen<S: Screen>(
-    _trigger: On<SwitchToScreen<S>>,
+    trigger: On<SwitchToScreen<S>>,
     registry: Res<ScreenRegistry>,
     mut commands: Commands,
 ) {
     let id = registry
         .get(&TypeId::of::<S>())
         .map_err(|_| ScreenError::NoSuchScreen(S::name()));
-    commands.write_message(SwitchToScreenMsg(id.unwrap()));
+    let mut msg = SwitchToScreenMsg::new(id.unwrap()).with_params(Box::new(trigger.params.clone()));
+    msg.transition = trigger.transition.clone();
+    msg.force = trigger.force;
+    commands.write_message(msg);
 }
 
+pub(crate) fn on_preload_screen<S: Screen>(
+    _trigger: On<PreloadScreen<S>>,
+    mut data: ScreenInfoMut<S>,
+) {
+    data.preload();
+}
+pub(crate) fn on_cancel_preload<S: Screen>(
+    _trigger: On<CancelPreload<S>>,
+    mut data: ScreenInfoMut<S>,
+) {
+    data.cancel_preload();
+}
+pub(crate) fn on_pause_screen<S: Screen>(_trigger: On<PauseScreen<S>>, mut data: ScreenInfoMut<S>) {
+    data.pause();
+}
+pub(crate) fn on_resume_screen<S: Screen>(
+    _trigger: On<ResumeScreen<S>>,
+    mut data: ScreenInfoMut<S>,
+) {
+    data.resume();
+}
 pub(crate) fn on_finish_loading<S: Screen>(
     _trigger: On<FinishLoading<S>>,
     mut data: ScreenInfoMut<S>,
 ) {
     data.finish_loading();
 }
+pub(crate) fn on_fail_loading<S: Screen>(trigger: On<FailLoading<S>>, mut data: ScreenInfoMut<S>) {
+    data.fail_loading(&trigger.reason);
+}
 pub(crate) fn on_finish_unloading<S: Screen>(
     _trigger: On<FinishUnloading<S>>,
     mut data: ScreenInfoMut<S>,
@@ -59,15 +354,101 @@ pub(crate) fn on_finish_unloading<S: Screen>(
     data.finish_unloading();
 }
 
-fn run_schedules(mut data: ResMut<ScreenData>, mut commands: Commands, tick: SystemChangeTick) {
-    let all_clear = data.iter().filter_map(|info| info.as_ref()).all(|info| {
-        matches!(
-            info.state(),
-            ScreenState::Unloaded | ScreenState::LoadQueued
-        )
-    });
+/// Runs a schedule on behalf of the given screen. Every entity spawned while
+/// the schedule runs is claimed by the screen with a [ScreenOwner]. If `time`
+/// is set, it replaces [Time] while the schedule runs, see [ScreenClock].
+///
+/// The schedule runs inside a `screen` span with the screen's name and state.
+fn run_screen_schedule(
+    commands: &mut Commands,
+    label: impl ScheduleLabel,
+    id: ScreenId,
+    time: Option<Time>,
+) {
+    commands.queue(
+        (move |world: &mut World| -> Result {
+            let start = world.increment_change_tick();
+            let span = world
+                .resource::<ScreenData>()
+                .get(id)
+                .ok()
+                .map(|info| info_span!("screen", name = info.name(), state = ?info.state()));
+            let _span = span.map(|span| span.entered());
+            let global = time.and_then(|time| swap_time(world, time));
+            let result = world.try_run_schedule(label);
+            if let Some(global) = global {
+                swap_time(world, global);
+            }
+            result?;
+            world.run_system_cached_with(claim_spawned_entities, (id, start))?;
+            Ok(())
+        })
+        .handle_error_with(warn),
+    );
+}
+
+fn swap_time(world: &mut World, time: Time) -> Option<Time> {
+    let mut current = world.get_resource_mut::<Time>()?;
+    Some(std::mem::replace(&mut *current, time))
+}
+
+fn claim_spawned_entities(
+    In((id, start)): In<(ScreenId, Tick)>,
+    unowned: Query<(Entity, SpawnDetails), Without<ScreenOwner>>,
+    tick: SystemChangeTick,
+    mut commands: Commands,
+) {
+    for (entity, details) in unowned.iter() {
+        if details.spawn_tick().is_newer_than(start, tick.this_run()) {
+            commands.entity(entity).try_insert(ScreenOwner(id));
+        }
+    }
+}
+
+fn run_schedules(
+    mut data: ResMut<ScreenData>,
+    mut commands: Commands,
+    tick: SystemChangeTick,
+    transition: Res<ActiveTransition>,
+    fallback: Res<FallbackScreen>,
+    virtual_time: Option<Res<Time<Virtual>>>,
+) {
+    let delta = virtual_time.map(|time| time.delta()).unwrap_or_default();
+    let fallback = (*fallback)
+        .as_ref()
+        .and_then(|name| data.iter_some().find(|info| info.name() == name))
+        .map(|info| info.screen_id());
+
+    // Loading screens are shown while waiting, so don't wait for them, and
+    // preloads happen in the background.
+    let loading_screens = data
+        .iter_some()
+        .filter_map(|info| info.shown_loading_screen)
+        .collect_vec();
+    let all_clear = data
+        .iter_some()
+        .filter(|info| !loading_screens.contains(&info.screen_id()) && !info.preloading())
+        .all(|info| {
+            matches!(
+                info.state(),
+                ScreenState::Unloaded | ScreenState::LoadQueued
+            )
+        });
 
     for info in data.iter_mut().filter_map(|info| info.as_mut()) {
+        init_screen_scope(&mut commands, info);
+        let running = match info.state() {
+            ScreenState::Loading => true,
+            ScreenState::Ready => info.runs_update(),
+            _ => false,
+        };
+        // A stopped clock still advances by zero, so its delta is zero.
+        info.clock.advance(if running {
+            delta
+        } else {
+            std::time::Duration::ZERO
+        });
+        let time = Some(info.clock.as_time());
         match info.state() {
             ScreenState::Unloaded => {
                 if !info.initialized {
@@ -76,63 +457,137 @@ fn run_schedules(mut data: ResMut<ScreenData>, mut commands: Commands, tick: Sys
                     info.changed_at = tick.this_run();
                 }
                 if info.needs_update {
-                    commands.run_schedule(OnScreenUnloaded(info.type_id()));
+                    // Not a screen schedule: the screen no longer owns anything.
+                    commands.run_schedule(OnScreenUnloaded(info.key()));
                     info.needs_update = false;
                     info.changed_at = tick.this_run();
                 }
             }
             ScreenState::LoadQueued => {
                 if info.needs_update {
-                    commands.run_schedule(OnScreenLoadQueued(info.type_id()));
+                    run_screen_schedule(
+                        &mut commands,
+                        OnScreenLoadQueued(info.key()),
+                        info.screen_id(),
+                        time,
+                    );
                     info.needs_update = false;
                     info.changed_at = tick.this_run();
                 }
                 if all_clear {
                     info.load(tick.this_run());
+                    init_screen_scope(&mut commands, info);
                 }
             }
             ScreenState::Loading => {
-                commands.run_schedule(ScreenScheduleLabel::from_id(
-                    ScreenSchedule::Loading,
-                    info.type_id(),
-                ));
+                run_screen_schedule(
+                    &mut commands,
+                    ScreenScheduleLabel::from_key(ScreenSchedule::Loading, info.key()),
+                    info.screen_id(),
+                    time,
+                );
                 if info.needs_update {
-                    commands.run_schedule(OnScreenLoad(info.type_id()));
+                    run_screen_schedule(
+                        &mut commands,
+                        OnScreenLoad(info.key()),
+                        info.screen_id(),
+                        time,
+                    );
                     info.needs_update = false;
                     info.changed_at = tick.this_run();
                 }
-                if matches!(info.load_strategy(), LoadStrategy::Nonblocking) {
-                    commands.run_schedule(ScreenScheduleLabel::from_id(
-                        ScreenSchedule::Update,
-                        info.type_id(),
-                    ));
+                if matches!(info.load_strategy(), LoadStrategy::Nonblocking) && info.runs_update() {
+                    run_screen_schedule(
+                        &mut commands,
+                        ScreenScheduleLabel::from_key(ScreenSchedule::Update, info.key()),
+                        info.screen_id(),
+                        time,
+                    );
                 }
             }
             ScreenState::Ready => {
-                commands.run_schedule(ScreenScheduleLabel::from_id(
-                    ScreenSchedule::Update,
-                    info.type_id(),
-                ));
-                if info.needs_update {
-                    commands.run_schedule(OnScreenReady(info.type_id()));
+                if info.runs_update() {
+                    run_screen_schedule(
+                        &mut commands,
+                        ScreenScheduleLabel::from_key(ScreenSchedule::Update, info.key()),
+                        info.screen_id(),
+                        time,
+                    );
+                } else if info.covered() && info.covered_policy() == CoveredPolicy::Pause {
+                    run_screen_schedule(
+                        &mut commands,
+                        ScreenScheduleLabel::from_key(ScreenSchedule::Paused, info.key()),
+                        info.screen_id(),
+                        time,
+                    );
+                }
+                // A preloaded screen is not ready until it is switched to.
+                if info.needs_update && !info.preloading() {
+                    run_screen_schedule(
+                        &mut commands,
+                        OnScreenReady(info.key()),
+                        info.screen_id(),
+                        time,
+                    );
                     info.needs_update = false;
                     info.changed_at = tick.this_run();
                 }
             }
+            ScreenState::Paused => {
+                run_screen_schedule(
+                    &mut commands,
+                    ScreenScheduleLabel::from_key(ScreenSchedule::Paused, info.key()),
+                    info.screen_id(),
+                    time,
+                );
+            }
             ScreenState::Unloading => {
-                commands.run_schedule(ScreenScheduleLabel::from_id(
-                    ScreenSchedule::Unloading,
-                    info.type_id(),
-                ));
+                run_screen_schedule(
+                    &mut commands,
+                    ScreenScheduleLabel::from_key(ScreenSchedule::Unloading, info.key()),
+                    info.screen_id(),
+                    time,
+                );
                 if info.needs_update {
-                    commands.run_schedule(OnScreenUnload(info.type_id()));
+                    run_screen_schedule(
+                        &mut commands,
+                        OnScreenUnload(info.key()),
+                        info.screen_id(),
+                        time,
+                    );
                     info.needs_update = false;
                     info.changed_at = tick.this_run();
                 }
             }
-            ScreenState::Cleanup => {
+            ScreenState::Failed(reason) => {
                 if info.needs_update {
-                    commands.run_schedule(OnScreenCleanup(info.type_id()));
+                    error!("{} failed to load: {reason}", info.name());
+                    run_screen_schedule(
+                        &mut commands,
+                        OnScreenFailed(info.key()),
+                        info.screen_id(),
+                        time,
+                    );
+                    if info.preloading() {
+                        // Not switched to yet, so there is nothing to fall back from.
+                        info.unload(tick.this_run());
+                        continue;
+                    }
+                    match fallback {
+                        Some(id) if id != info.screen_id() => {
+                            commands.write_message(SwitchToScreenMsg::new(id).forced());
+                        }
+                        Some(_) => error!("The fallback screen failed to load!"),
+                        None => warn!("No FallbackScreen set, staying on {}", info.name()),
+                    }
+                    info.needs_update = false;
+                    info.changed_at = tick.this_run();
+                }
+            }
+            ScreenState::Cleanup => {
+                if info.needs_update && !transition.holds_cleanup(info.screen_id()) {
+                    // Not a screen schedule: the screen no longer owns anything.
+                    commands.run_schedule(OnScreenCleanup(info.key()));
                     info.needs_update = false;
                     info.changed_at = tick.this_run();
                 }
@@ -141,42 +596,353 @@ fn run_schedules(mut data: ResMut<ScreenData>, mut commands: Commands, tick: Sys
     }
 }
 
+/// Updates the [ScreenLoadProgress] of every loading screen which tracks
+/// assets, and finishes loading once all of them have loaded.
+fn track_load_progress(world: &mut World) {
+    let asset_server = rq!(world.get_resource::<AssetServer>()).clone();
+    let mut loaded = vec![];
+    for info in world.resource_mut::<ScreenData>().iter_some_mut() {
+        if info.state() != ScreenState::Loading || !info.tracks_assets() {
+            continue;
+        }
+        if info.update_load_progress(&asset_server).is_done() {
+            loaded.push((info.screen_id(), info.take_on_loaded()));
+        }
+    }
+    for (id, on_loaded) in loaded {
+        for callback in on_loaded {
+            callback(world);
+        }
+        let tick = world.change_tick();
+        c!(world.resource_mut::<ScreenData>().get_mut(id)).finish_loading(tick);
+    }
+}
+
+/// Loads the loading screen of every queued or loading screen, and unloads it
+/// once that screen has left those states. See
+/// [ScreenScopeBuilder::with_loading_screen].
+fn run_loading_screens(mut data: ResMut<ScreenData>, tick: SystemChangeTick) {
+    let is_loading = |info: &ScreenInfo| {
+        !info.preloading() && matches!(info.state(), ScreenState::LoadQueued | ScreenState::Loading)
+    };
+    let show = data
+        .iter_some()
+        .filter(|info| is_loading(info))
+        .filter_map(|info| Some((info.screen_id(), info.loading_screen()?)))
+        .collect_vec();
+    // Don't unload a loading screen which is still needed by another screen.
+    let hide = data
+        .iter_some_mut()
+        .filter(|info| !is_loading(info))
+        .filter_map(|info| info.shown_loading_screen.take())
+        .collect_vec();
+    for id in hide {
+        let needed = show.iter().any(|(_, loading_screen)| *loading_screen == id);
+        if !needed {
+            c!(data.get_mut(id)).unload(tick.this_run());
+        }
+    }
+    for (id, loading_id) in show {
+        let info = c!(data.get_mut(loading_id));
+        info.load(tick.this_run());
+        // Try again next frame if the loading screen is still cleaning up.
+        if matches!(
+            info.state(),
+            ScreenState::Loading | ScreenState::Ready | ScreenState::Paused
+        ) {
+            c!(data.get_mut(id)).shown_loading_screen = Some(loading_id);
+        }
+    }
+}
+
+/// Advances the [ActiveTransition]. The Out phase holds outgoing screens in
+/// [ScreenState::Cleanup], and the In phase waits for the incoming screen.
+fn run_transition(world: &mut World) {
+    let interrupted = std::mem::take(&mut world.resource_mut::<ActiveTransition>().interrupted);
+    for transition in interrupted {
+        transition.finish(world);
+    }
+    let mut active = world.resource_mut::<ActiveTransition>();
+    let mut info = rq!(active.current.take());
+    if !info.started {
+        info.transition.start(world);
+        info.started = true;
+    }
+    let delta = world.resource::<Time<Real>>().delta();
+    let progress = |elapsed: std::time::Duration, duration: std::time::Duration| {
+        if duration.is_zero() {
+            1.
+        } else {
+            (elapsed.as_secs_f32() / duration.as_secs_f32()).min(1.)
+        }
+    };
+    match info.phase {
+        TransitionPhase::Out => {
+            info.elapsed += delta;
+            let duration = info.transition.out_duration();
+            info.transition.update(
+                world,
+                TransitionPhase::Out,
+                progress(info.elapsed, duration),
+            );
+            if info.elapsed >= duration && info.transition.out_finished(world) {
+                info.phase = TransitionPhase::In;
+                info.elapsed = std::time::Duration::ZERO;
+            }
+        }
+        TransitionPhase::In => {
+            let target = world.resource::<ScreenData>().get(info.to).ok();
+            let waiting = target.is_some_and(|target| match target.state() {
+                ScreenState::LoadQueued => true,
+                ScreenState::Loading => {
+                    matches!(target.load_strategy(), LoadStrategy::Blocking)
+                }
+                _ => false,
+            });
+            if !waiting {
+                info.elapsed += delta;
+            }
+            let duration = info.transition.in_duration();
+            info.transition
+                .update(world, TransitionPhase::In, progress(info.elapsed, duration));
+            if !waiting && info.elapsed >= duration {
+                info.transition.finish(world);
+                return;
+            }
+        }
+    }
+    world.resource_mut::<ActiveTransition>().current = Some(info);
+}
+
+fn record_screen_metrics(
+    data: Res<ScreenData>,
+    mut metrics: ResMut<ScreenMetrics>,
+    time: Option<Res<Time<Real>>>,
+) {
+    let now = time.map(|time| time.elapsed()).unwrap_or_default();
+    metrics.next_frame();
+    for info in data.iter_some() {
+        metrics.sample(info, now);
+    }
+}
+
 fn run_fixed_schedules(mut registry: ResMut<ScreenData>, mut commands: Commands) {
     for data in registry.iter_mut().filter_map(|d| d.as_mut()) {
-        match data.state() {
+        let runs = match data.state() {
             ScreenState::Loading => {
-                if matches!(data.load_strategy(), LoadStrategy::Nonblocking) {
-                    commands.run_schedule(ScreenScheduleLabel::from_id(
-                        ScreenSchedule::FixedUpdate,
-                        data.type_id(),
-                    ));
-                }
-            }
-            ScreenState::Ready => {
-                commands.run_schedule(ScreenScheduleLabel::from_id(
-                    ScreenSchedule::FixedUpdate,
-                    data.type_id(),
-                ));
+                matches!(data.load_strategy(), LoadStrategy::Nonblocking) && data.runs_update()
             }
-            _ => {}
+            ScreenState::Ready => data.runs_update(),
+            _ => false,
+        };
+        if !runs {
+            continue;
         }
+        // The fixed timestep stays the same, so a scaled clock runs the
+        // schedule more or less often instead.
+        for _ in 0..data.clock.fixed_steps() {
+            run_screen_schedule(
+                &mut commands,
+                ScreenScheduleLabel::from_key(ScreenSchedule::FixedUpdate, data.key()),
+                data.screen_id(),
+                None,
+            );
+        }
+    }
+}
+
+/// Mirrors the screen's state into its [ScreenStateOf] whenever it changes.
+/// Once the screen is unregistered, it is mirrored as Unloaded.
+/// NOTE: This is registered in scope.rs
+pub(crate) fn mirror_screen_state<S: Screen>(
+    registry: Res<ScreenRegistry>,
+    data: Res<ScreenData>,
+    state: Res<State<ScreenStateOf<S>>>,
+    mut next: ResMut<NextState<ScreenStateOf<S>>>,
+    tick: SystemChangeTick,
+) {
+    let info = registry
+        .get(&TypeId::of::<S>())
+        .and_then(|id| data.get(id))
+        .ok();
+    let Some(info) = info else {
+        if state.state != ScreenState::Unloaded {
+            next.set(ScreenStateOf::unloaded());
+        }
+        return;
+    };
+    if !info
+        .changed_at()
+        .is_newer_than(tick.last_run(), tick.this_run())
+    {
+        return;
     }
+    if state.state != info.state() {
+        next.set(ScreenStateOf::new(info.state()));
+    }
+}
+
+pub(crate) fn add_state_mirror<S: Screen>(app: &mut App) {
+    // Already mirrored if the screen was registered before.
+    if app.world().contains_resource::<State<ScreenStateOf<S>>>() {
+        return;
+    }
+    app.init_state::<ScreenStateOf<S>>();
+    // Screens change state in Update and when switching in PostUpdate.
+    app.add_systems(PostUpdate, mirror_screen_state::<S>.after(handle_stack_msg));
 }
 
 pub(crate) fn initial_screen(
     mut commands: Commands,
     initial_screen: Res<InitialScreen>,
     screens: Screens,
+    persistence: Option<Res<ScreenPersistence>>,
+    registry: Res<AppTypeRegistry>,
 ) {
+    if let Some(persistence) = persistence.filter(|p| p.resume) {
+        match persistence.load() {
+            Ok(Some(saved)) => match saved.to_switch_msg(&screens, &registry.read()) {
+                Some(msg) => {
+                    commands.write_message(msg);
+                    return;
+                }
+                None => warn!("Saved screen {} no longer exists", saved.name),
+            },
+            Ok(None) => {}
+            Err(e) => warn!("{e}"),
+        }
+    }
     if let Some(initial_screen) = (*initial_screen).as_ref() {
         let info = r!(screens.get_by_name(initial_screen));
-        commands.write_message(SwitchToScreenMsg(info.screen_id()));
+        commands.write_message(SwitchToScreenMsg::new(info.screen_id()));
+    }
+}
+
+/// Gives every entity of a scene spawned beneath a screen-owned entity to the
+/// same screen, so it is cleaned up along with its root. Scenes are spawned
+/// outside of the screens' schedules, so they are not claimed otherwise. See
+/// [ScreenScopeBuilder::with_scene].
+fn claim_scene_entities(
+    ready: On<SceneInstanceReady>,
+    owners: Query<&ScreenOwner>,
+    children: Query<&Children>,
+    unowned: Query<(), Without<ScreenOwner>>,
+    mut commands: Commands,
+) {
+    let owner = *rq!(owners.get(ready.entity));
+    for entity in children.iter_descendants(ready.entity) {
+        if unowned.contains(entity) {
+            commands.entity(entity).try_insert(owner);
+        }
+    }
+}
+
+/// Saves the [CurrentScreen], see [ScreenPersistencePlugin].
+pub(crate) fn save_current_screen(
+    current: Res<CurrentScreen>,
+    screens: Screens,
+    persistence: Res<ScreenPersistence>,
+    registry: Res<AppTypeRegistry>,
+) {
+    let id = rq!(current.get_id());
+    let info = r!(screens.get_by_id(id));
+    let saved = PersistedScreen::new(info, &registry.read());
+    if let Err(e) = persistence.save(&saved) {
+        warn!("{e}");
+    }
+}
+
+/// Replaces the [ScreenFlow] once its asset has loaded or changed.
+fn apply_screen_flow(
+    mut events: MessageReader<AssetEvent<ScreenFlow>>,
+    handle: Res<ScreenFlowHandle>,
+    flows: Res<Assets<ScreenFlow>>,
+    mut commands: Commands,
+) {
+    for event in events.read() {
+        if let AssetEvent::LoadedWithDependencies { id } | AssetEvent::Modified { id } = event
+            && *id == handle.id()
+        {
+            commands.insert_resource(c!(flows.get(*id)).clone());
+        }
+    }
+}
+
+/// Reports screens in the [ScreenFlow] which are not registered, and
+/// registered screens which can never be switched to.
+fn check_screen_flow(
+    flow: Res<ScreenFlow>,
+    data: Res<ScreenData>,
+    initial_screen: Res<InitialScreen>,
+    fallback: Res<FallbackScreen>,
+) {
+    let names = data.iter_some().map(|info| info.name()).collect_vec();
+    for name in flow.screens() {
+        if !names.contains(&name) {
+            warn!("ScreenFlow contains {name}, which is not registered");
+        }
+    }
+    // Loading screens are shown without switching to them.
+    let loading_screens = data
+        .iter_some()
+        .filter_map(|info| data.get(info.loading_screen()?).ok())
+        .map(|info| info.name());
+    let roots = (*initial_screen)
+        .as_deref()
+        .into_iter()
+        .chain((*fallback).as_deref())
+        .chain(loading_screens);
+    for name in flow.unreachable(roots, names.iter().copied()) {
+        warn!("{name} cannot be reached in the ScreenFlow");
     }
 }
 
 pub(crate) fn plugin(app: &mut App) {
     app.add_systems(Startup, initial_screen);
-    app.add_systems(PostUpdate, handle_switch_msg);
-    app.add_systems(Update, run_schedules);
-    app.add_systems(FixedUpdate, run_fixed_schedules);
+    app.add_systems(
+        PreUpdate,
+        (
+            apply_screen_flow.run_if(resource_exists::<ScreenFlowHandle>),
+            check_screen_flow.run_if(resource_exists_and_changed::<ScreenFlow>),
+        )
+            .chain(),
+    );
+    app.add_systems(
+        PostUpdate,
+        (handle_switch_msg, handle_stack_msg)
+            .chain()
+            .in_set(ScreenSet::Lifecycle),
+    );
+    app.add_observer(on_pop_screen);
+    app.add_observer(record_history);
+    app.add_observer(on_go_back);
+    app.add_observer(claim_scene_entities);
+    app.add_systems(
+        Update,
+        (
+            track_load_progress,
+            run_loading_screens,
+            run_transition,
+            run_schedules,
+        )
+            .chain()
+            .in_set(ScreenSet::Lifecycle),
+    );
+    app.add_systems(
+        FixedUpdate,
+        run_fixed_schedules.in_set(ScreenSet::Lifecycle),
+    );
+    for schedule in [Update.intern(), FixedUpdate.intern()] {
+        app.configure_sets(
+            schedule,
+            (
+                ScreenSet::Loading.run_if(any_screen_loading()),
+                ScreenSet::Ready.run_if(current_screen_has_state(ScreenState::Ready)),
+                ScreenSet::Paused.run_if(current_screen_has_state(ScreenState::Paused)),
+                ScreenSet::Unloading.run_if(any_screen_unloading()),
+            )
+                .after(ScreenSet::Lifecycle),
+        );
+    }
+    app.add_systems(Last, record_screen_metrics);
 }
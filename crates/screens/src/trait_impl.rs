@@ -1,4 +1,5 @@
 pub use crate::prelude::*;
+use bevy::reflect::Reflectable;
 
 /// How should the screen load its assets?
 /// If `LoadingStrategy` is Blocking, the screen's systems will not run until
@@ -13,8 +14,41 @@ pub enum LoadStrategy {
     Nonblocking,
 }
 
+/// What should a screen do while another screen is pushed on top of it?
+/// See [PushScreen].
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Reflect)]
+pub enum CoveredPolicy {
+    /// Keep running the screen's [Update] and [FixedUpdate] schedules.
+    #[default]
+    Run,
+    /// Stop running the screen's [Update] and [FixedUpdate] schedules, and
+    /// run its [ScreenSchedule::Paused] schedule instead, as with
+    /// [PauseScreen]. Its entities are left as they are.
+    Pause,
+    /// Stop running all of the screen's per-frame schedules, but keep
+    /// rendering its entities beneath the covering screen.
+    RenderOnly,
+}
+
+/// Can the screen be left? Returned by the screen's exit guard, see
+/// [ScreenScopeBuilder::with_exit_guard].
+#[derive(Default, Copy, Clone, Debug, PartialEq, Eq, Reflect)]
+pub enum ExitDecision {
+    /// Let the switch happen.
+    #[default]
+    Allow,
+    /// Cancel the switch and trigger [ScreenSwitchDenied].
+    Deny,
+    /// Hold the switch and ask again next frame, e.g. until a save finishes.
+    Defer,
+}
+
 /// Implementation trait for Screen components.
 pub trait Screen: Component + Reflect + Default + Sized + Send + Sync + 'static {
+    /// Parameters passed to the screen when switching to it, e.g. a level id
+    /// or a save slot. Read them with [ScreenParams]. Defaults to `()`.
+    type Params: FromReflect + Reflectable + Clone + Default = ();
+
     /// The screen's public name. Used for serialization. Defaults to the short type path.
     fn name() -> String {
         let default = Self::default();
@@ -27,3 +61,15 @@ pub trait Screen: Component + Reflect + Default + Sized + Send + Sync + 'static
     /// Don't forget to register your screen! [App::register_screen]
     fn builder(builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self>;
 }
+
+/// Stand-in [Screen] type for screens defined at runtime, e.g. by tools, mods
+/// or data files. Dynamic screens are registered with
+/// [RegisterScreen::register_dynamic_screen] and are identified by their
+/// [ScreenId] or name, not by this type. Do not register it directly.
+#[derive(Component, Reflect, Default, Clone, Copy, Debug)]
+pub struct DynamicScreen;
+impl Screen for DynamicScreen {
+    fn builder(builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+    }
+}
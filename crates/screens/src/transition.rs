@@ -0,0 +1,224 @@
+use std::{fmt::Debug, time::Duration};
+
+pub use crate::prelude::*;
+use bevy::{
+    render::view::screenshot::{Screenshot, ScreenshotCaptured},
+    window::PrimaryWindow,
+};
+
+/// Which half of a [ScreenTransition] is running.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Reflect)]
+pub enum TransitionPhase {
+    /// Runs while the outgoing screens are unloading. Outgoing screens will not
+    /// be cleaned up until this phase is finished.
+    Out,
+    /// Runs once the incoming screen is [ScreenState::Ready], or
+    /// [ScreenState::Loading] if it uses [LoadStrategy::Nonblocking].
+    In,
+}
+
+/// A visual effect played when switching screens. The transition is split in
+/// two [TransitionPhase]s: the Out phase covers the outgoing screens, and the
+/// In phase reveals the incoming screen.
+///
+/// Set a screen's default transition with
+/// [ScreenScopeBuilder::with_transition], or override it on a single switch
+/// with [SwitchToScreen::with_transition]. Transitions are not played when
+/// pushing or popping screens.
+///
+/// Anything spawned by the transition is not owned by a screen, so make sure
+/// to despawn it in [ScreenTransition::finish].
+pub trait ScreenTransition: Debug + Send + Sync + 'static {
+    /// How long the Out phase lasts.
+    fn out_duration(&self) -> Duration;
+    /// How long the In phase lasts.
+    fn in_duration(&self) -> Duration;
+    /// Called once before the first update. Spawn any overlays here. If there
+    /// are no outgoing screens, e.g. on the initial screen, the Out phase is
+    /// skipped.
+    fn start(&self, world: &mut World);
+    /// Called every frame with the current phase and its progress from 0 to 1.
+    fn update(&self, world: &mut World, phase: TransitionPhase, progress: f32);
+    /// Can the Out phase end? Called every frame once its duration has
+    /// elapsed. Defaults to true.
+    fn out_finished(&self, _world: &mut World) -> bool {
+        true
+    }
+    /// Called once after the In phase, or when the transition is interrupted
+    /// by another screen switch.
+    fn finish(&self, world: &mut World);
+}
+
+/// Marks the full-screen overlay spawned by the built-in transitions.
+#[derive(Component, Debug, Reflect, Clone, Copy, Default, PartialEq)]
+pub struct TransitionOverlay;
+
+fn overlay_bundle() -> impl Bundle {
+    (
+        TransitionOverlay,
+        Node {
+            position_type: PositionType::Absolute,
+            width: Val::Percent(100.),
+            height: Val::Percent(100.),
+            ..Default::default()
+        },
+        GlobalZIndex(i32::MAX),
+    )
+}
+
+fn despawn_overlays(world: &mut World) {
+    let overlays = world
+        .query_filtered::<Entity, With<TransitionOverlay>>()
+        .iter(world)
+        .collect_vec();
+    for entity in overlays {
+        world.despawn(entity);
+    }
+}
+
+/// Fades the outgoing screen into a solid colour, then fades the colour out
+/// over the incoming screen.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FadeToColor {
+    #[allow(missing_docs)]
+    pub color: Color,
+    /// Duration of the [TransitionPhase::Out] phase.
+    pub out_duration: Duration,
+    /// Duration of the [TransitionPhase::In] phase.
+    pub in_duration: Duration,
+}
+impl FadeToColor {
+    /// Fades to the given colour. The duration is split evenly between both phases.
+    pub fn new(color: impl Into<Color>, duration: Duration) -> Self {
+        Self {
+            color: color.into(),
+            out_duration: duration / 2,
+            in_duration: duration / 2,
+        }
+    }
+}
+impl Default for FadeToColor {
+    fn default() -> Self {
+        Self::new(Color::BLACK, Duration::from_millis(500))
+    }
+}
+impl ScreenTransition for FadeToColor {
+    fn out_duration(&self) -> Duration {
+        self.out_duration
+    }
+    fn in_duration(&self) -> Duration {
+        self.in_duration
+    }
+    fn start(&self, world: &mut World) {
+        world.spawn((
+            Name::new("FadeToColor"),
+            overlay_bundle(),
+            BackgroundColor(self.color.with_alpha(0.)),
+        ));
+    }
+    fn update(&self, world: &mut World, phase: TransitionPhase, progress: f32) {
+        let alpha = match phase {
+            TransitionPhase::Out => progress,
+            TransitionPhase::In => 1. - progress,
+        };
+        let mut overlays = world.query_filtered::<&mut BackgroundColor, With<TransitionOverlay>>();
+        for mut color in overlays.iter_mut(world) {
+            color.0 = self.color.with_alpha(alpha * self.color.alpha());
+        }
+    }
+    fn finish(&self, world: &mut World) {
+        despawn_overlays(world);
+    }
+}
+
+/// Blends the last frame of the outgoing screen into the incoming screen.
+///
+/// This captures a [Screenshot] of the primary window, so the Out phase lasts
+/// until the screenshot is ready. Without a primary window this is a hard cut.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Crossfade {
+    #[allow(missing_docs)]
+    pub duration: Duration,
+}
+impl Crossfade {
+    #[allow(missing_docs)]
+    pub fn new(duration: Duration) -> Self {
+        Self { duration }
+    }
+}
+impl Default for Crossfade {
+    fn default() -> Self {
+        Self::new(Duration::from_millis(500))
+    }
+}
+
+fn has_primary_window(world: &mut World) -> bool {
+    world
+        .query_filtered::<(), With<PrimaryWindow>>()
+        .iter(world)
+        .next()
+        .is_some()
+}
+
+/// Has the [Crossfade] overlay received its screenshot?
+#[derive(Component, Debug, Clone, Copy, Default)]
+struct CrossfadeCaptured(bool);
+
+fn on_crossfade_captured(
+    captured: On<ScreenshotCaptured>,
+    mut images: ResMut<Assets<Image>>,
+    mut overlays: Query<
+        (&mut ImageNode, &mut Visibility, &mut CrossfadeCaptured),
+        With<TransitionOverlay>,
+    >,
+) {
+    let handle = images.add(captured.image.clone());
+    for (mut node, mut visibility, mut captured) in overlays.iter_mut() {
+        node.image = handle.clone();
+        *visibility = Visibility::Inherited;
+        captured.0 = true;
+    }
+}
+
+impl ScreenTransition for Crossfade {
+    fn out_duration(&self) -> Duration {
+        Duration::ZERO
+    }
+    fn in_duration(&self) -> Duration {
+        self.duration
+    }
+    fn start(&self, world: &mut World) {
+        world.spawn((
+            Name::new("Crossfade"),
+            overlay_bundle(),
+            ImageNode::default(),
+            Visibility::Hidden,
+            CrossfadeCaptured::default(),
+        ));
+        if has_primary_window(world) {
+            world
+                .spawn(Screenshot::primary_window())
+                .observe(on_crossfade_captured);
+        }
+    }
+    fn update(&self, world: &mut World, phase: TransitionPhase, progress: f32) {
+        let alpha = match phase {
+            TransitionPhase::Out => 1.,
+            TransitionPhase::In => 1. - progress,
+        };
+        let mut overlays = world.query_filtered::<&mut ImageNode, With<TransitionOverlay>>();
+        for mut node in overlays.iter_mut(world) {
+            node.color = node.color.with_alpha(alpha);
+        }
+    }
+    fn out_finished(&self, world: &mut World) -> bool {
+        !has_primary_window(world)
+            || world
+                .query::<&CrossfadeCaptured>()
+                .iter(world)
+                .any(|captured| captured.0)
+    }
+    fn finish(&self, world: &mut World) {
+        despawn_overlays(world);
+    }
+}
@@ -91,3 +91,63 @@ fn test_conditionals() {
     );
     assert!(app.run().is_success());
 }
+
+#[derive(Resource, Default, Debug)]
+struct GenericConditionals {
+    any_screen_loading: bool,
+    any_screen_unloading: bool,
+    current_screen_is: bool,
+    screen_named_has_state: bool,
+    state_changes: u32,
+    loading_set: bool,
+    ready_set: bool,
+    unloading_set: bool,
+}
+
+#[test]
+fn test_generic_conditionals() {
+    let mut app = get_test_app::<ConditionalsScreen>();
+    app.init_resource::<GenericConditionals>();
+    app.add_systems(
+        Update,
+        (
+            (|mut res: ResMut<GenericConditionals>| res.any_screen_loading = true)
+                .run_if(any_screen_loading()),
+            (|mut res: ResMut<GenericConditionals>| res.any_screen_unloading = true)
+                .run_if(any_screen_unloading()),
+            (|mut res: ResMut<GenericConditionals>| res.current_screen_is = true)
+                .run_if(current_screen_is(ConditionalsScreen::name())),
+            (|mut res: ResMut<GenericConditionals>| res.screen_named_has_state = true).run_if(
+                screen_named_has_state(ConditionalsScreen::name(), ScreenState::Ready),
+            ),
+            (|mut res: ResMut<GenericConditionals>| res.state_changes += 1)
+                .run_if(screen_state_changed()),
+            (|mut res: ResMut<GenericConditionals>| res.loading_set = true)
+                .in_set(ScreenSet::Loading),
+            (|mut res: ResMut<GenericConditionals>| res.ready_set = true).in_set(ScreenSet::Ready),
+            (|mut res: ResMut<GenericConditionals>| res.unloading_set = true)
+                .in_set(ScreenSet::Unloading),
+        ),
+    );
+    app.add_systems(
+        on_screen_unloaded::<ConditionalsScreen>(),
+        |res: Res<GenericConditionals>, mut commands: Commands| {
+            let ok = res.any_screen_loading
+                && res.any_screen_unloading
+                && res.current_screen_is
+                && res.screen_named_has_state
+                // Loading, Ready and Unloading at least
+                && res.state_changes >= 3
+                && res.loading_set
+                && res.ready_set
+                && res.unloading_set;
+            if ok {
+                commands.write_message(AppExit::Success);
+            } else {
+                error!(?res, "Generic conditions did not run.");
+                commands.write_message(AppExit::error());
+            }
+        },
+    );
+    assert!(app.run().is_success());
+}
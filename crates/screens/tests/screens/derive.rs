@@ -0,0 +1,49 @@
+use crate::prelude::*;
+
+#[derive(Resource, Debug, Default)]
+struct DeriveStatus {
+    loaded: bool,
+    first: u32,
+    second: u32,
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect, Screen)]
+#[screen(name = "Derived", load_strategy = "nonblocking", skip_load = false)]
+#[screen(loading = finish, update = (first, second).chain(), on_ready = ready)]
+struct DerivedScreen;
+
+fn finish(mut status: ResMut<DeriveStatus>, mut commands: Commands) {
+    status.loaded = true;
+    commands.trigger(finish_loading::<DerivedScreen>());
+}
+fn first(mut status: ResMut<DeriveStatus>) {
+    status.first += 1;
+}
+fn second(mut status: ResMut<DeriveStatus>) {
+    status.second += 1;
+}
+fn ready(status: Res<DeriveStatus>, data: ScreenInfoRef<DerivedScreen>, mut commands: Commands) {
+    let data = data.data();
+    if DerivedScreen::name() == "Derived"
+        && data.load_strategy() == LoadStrategy::Nonblocking
+        && status.loaded
+        && status.first > 0
+        && status.first == status.second
+    {
+        commands.write_message(AppExit::Success);
+    } else {
+        error!(
+            ?status,
+            name = DerivedScreen::name(),
+            "Derived screen misconfigured."
+        );
+        commands.write_message(AppExit::error());
+    }
+}
+
+#[test]
+fn derive_screen() {
+    let mut app = get_test_app::<DerivedScreen>();
+    app.init_resource::<DeriveStatus>();
+    assert!(app.run().is_success());
+}
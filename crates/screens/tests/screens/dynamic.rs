@@ -0,0 +1,103 @@
+use crate::prelude::*;
+use bevy::ecs::schedule::ScheduleLabel;
+
+#[derive(Resource, Debug, Default)]
+struct DynamicRan(u32);
+
+#[test]
+fn dynamic_screen() {
+    let mut app = App::new();
+    app.add_plugins((TestRunnerPlugin::default(), ScreenPlugin));
+    app.init_resource::<DynamicRan>();
+    app.register_screen::<EmptyScreen>();
+    app.register_dynamic_screen("Dynamic", |mut builder| {
+        builder
+            .add_systems(ScreenSchedule::Update, |mut ran: ResMut<DynamicRan>| {
+                ran.0 += 1;
+            })
+            .add_systems(ScreenSchedule::OnReady, |mut commands: Commands| {
+                commands.trigger(switch_to_screen::<EmptyScreen>());
+            });
+        builder
+    });
+    // Name collisions are not registered.
+    app.register_dynamic_screen("Dynamic", |builder| builder);
+    app.insert_resource(InitialScreen::from_name("Dynamic".into()));
+    app.add_systems(
+        on_screen_ready::<EmptyScreen>(),
+        |data: Res<ScreenData>, ran: Res<DynamicRan>, mut commands: Commands| {
+            let dynamic = data
+                .iter_some()
+                .filter(|info| info.name() == "Dynamic")
+                .collect::<Vec<_>>();
+            if ran.0 > 0
+                && dynamic.len() == 1
+                && dynamic[0].type_id().is_none()
+                && dynamic[0].state() == ScreenState::Unloaded
+            {
+                commands.write_message(AppExit::Success);
+            } else {
+                error!(ran = ran.0, "Dynamic screen did not run.");
+                commands.write_message(AppExit::error());
+            }
+        },
+    );
+    assert!(app.run().is_success());
+}
+
+#[test]
+fn schedules_keyed_by_screen_key() {
+    assert_eq!(
+        on_screen_ready::<EmptyScreen>().intern(),
+        OnScreenReady(ScreenKey::of::<EmptyScreen>()).intern()
+    );
+}
+
+#[test]
+fn screen_ids_per_app() {
+    // Every app allocates its own ids, so they stay dense. Only one app per
+    // test can capture logs, so these use MinimalPlugins.
+    for _ in 0..2 {
+        let mut app = App::new();
+        app.add_plugins((MinimalPlugins, ScreenPlugin));
+        app.register_screen::<LifecycleScreen>();
+        app.register_dynamic_screen("Dynamic", |builder| builder);
+        app.register_screen::<EmptyScreen>();
+        let ids = app
+            .world()
+            .resource::<ScreenData>()
+            .iter()
+            .map(|info| info.as_ref().map(|info| *info.screen_id()))
+            .collect::<Vec<_>>();
+        assert_eq!(ids, [Some(0), Some(1), Some(2)]);
+    }
+}
+
+#[test]
+fn register_from_world() {
+    let mut app = App::new();
+    app.add_plugins((TestRunnerPlugin::default(), ScreenPlugin));
+    app.register_screen::<EmptyScreen>();
+    app.insert_resource(InitialScreen::new::<EmptyScreen>());
+    app.add_systems(
+        on_screen_ready::<EmptyScreen>(),
+        |mut commands: Commands| {
+            commands.queue(|world: &mut World| {
+                world.register_dynamic_screen("Late", |mut builder| {
+                    builder.add_systems(ScreenSchedule::OnReady, |mut commands: Commands| {
+                        commands.write_message(AppExit::Success);
+                    });
+                    builder
+                });
+                let id = world
+                    .resource::<ScreenData>()
+                    .iter_some()
+                    .find(|info| info.name() == "Late")
+                    .map(|info| info.screen_id())
+                    .unwrap();
+                world.write_message(SwitchToScreenMsg::new(id));
+            });
+        },
+    );
+    assert!(app.run().is_success());
+}
@@ -95,3 +95,97 @@ fn test_scoped_entities() {
     app.register_screen::<EmptyScreen>();
     assert!(app.run().is_success());
 }
+
+#[derive(Component, PartialEq, Debug, Copy, Clone)]
+enum Owned {
+    ByFirst,
+    BySecond,
+}
+
+/// Loads [OverlapSecondScreen] alongside itself, then unloads while the second
+/// screen is still active.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+pub struct OverlapFirstScreen;
+impl Screen for OverlapFirstScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(
+                ScreenSchedule::Loading,
+                |mut commands: Commands, mut data: ScreenInfoMut<Self>| {
+                    commands.spawn((Owned::ByFirst, children![Owned::ByFirst]));
+                    data.finish_loading();
+                },
+            )
+            .add_systems(
+                ScreenSchedule::OnReady,
+                |mut second: ScreenInfoMut<OverlapSecondScreen>| {
+                    second.load();
+                },
+            )
+            .add_systems(
+                ScreenSchedule::OnUnloaded,
+                |mut commands: Commands,
+                 query: Query<&Owned>,
+                 mut second: ScreenInfoMut<OverlapSecondScreen>| {
+                    let ok = !query.iter().contains(&Owned::ByFirst)
+                        && query.iter().filter(|o| **o == Owned::BySecond).count() == 2;
+                    if ok {
+                        second.unload();
+                    } else {
+                        error!("Unloading the first screen removed the wrong entities.");
+                        commands.log_hierarchy();
+                        commands.write_message(AppExit::error());
+                    }
+                },
+            );
+        builder
+    }
+}
+
+/// Loaded while [OverlapFirstScreen] is still ready.
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+pub struct OverlapSecondScreen;
+impl Screen for OverlapSecondScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(
+                ScreenSchedule::Loading,
+                |mut commands: Commands, mut data: ScreenInfoMut<Self>| {
+                    commands.spawn((Owned::BySecond, children![Owned::BySecond]));
+                    data.finish_loading();
+                },
+            )
+            .add_systems(
+                ScreenSchedule::OnReady,
+                |mut first: ScreenInfoMut<OverlapFirstScreen>| {
+                    first.unload();
+                },
+            )
+            .add_systems(
+                ScreenSchedule::OnUnloaded,
+                |mut commands: Commands, query: Query<&Owned>| {
+                    if query.is_empty() {
+                        commands.write_message(AppExit::Success);
+                    } else {
+                        error!("Unloading the second screen left entities behind.");
+                        commands.log_hierarchy();
+                        commands.write_message(AppExit::error());
+                    }
+                },
+            );
+        builder
+    }
+}
+
+#[test]
+fn test_overlapping_screens() {
+    let mut app = get_test_app::<OverlapFirstScreen>();
+    app.register_screen::<OverlapSecondScreen>();
+    // unowned entities are left alone
+    app.world_mut().spawn(Name::new("Unowned"));
+    app.add_systems(
+        on_screen_unloaded::<OverlapSecondScreen>(),
+        |mut commands: Commands| commands.find_entity("Unowned"),
+    );
+    assert!(app.run().is_success());
+}
@@ -0,0 +1,88 @@
+use crate::prelude::*;
+
+#[derive(Resource, Debug, Default)]
+struct Guard {
+    decision: ExitDecision,
+    denied: u32,
+    deferred_frames: u32,
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct GuardedScreen;
+impl Screen for GuardedScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .with_exit_guard(|guard: Res<Guard>| guard.decision)
+            .add_systems(
+                ScreenSchedule::OnReady,
+                |mut guard: ResMut<Guard>, mut commands: Commands| {
+                    guard.decision = ExitDecision::Deny;
+                    commands.trigger(switch_to_screen::<EmptyScreen>());
+                },
+            )
+            .add_systems(ScreenSchedule::Update, |mut guard: ResMut<Guard>| {
+                if guard.decision == ExitDecision::Defer {
+                    guard.deferred_frames += 1;
+                    if guard.deferred_frames == 5 {
+                        guard.decision = ExitDecision::Allow;
+                    }
+                }
+            });
+        builder
+    }
+}
+
+#[test]
+fn exit_guard() {
+    let mut app = get_test_app::<GuardedScreen>();
+    app.init_resource::<Guard>();
+    app.add_observer(
+        |_: On<ScreenSwitchDenied>, mut guard: ResMut<Guard>, mut commands: Commands| {
+            guard.denied += 1;
+            guard.decision = ExitDecision::Defer;
+            commands.trigger(switch_to_screen::<EmptyScreen>());
+        },
+    );
+    app.add_systems(
+        on_screen_ready::<EmptyScreen>(),
+        |guard: Res<Guard>, mut commands: Commands| {
+            if guard.denied == 1 && guard.deferred_frames == 5 {
+                commands.write_message(AppExit::Success);
+            } else {
+                error!(?guard, "Exit guard was not respected.");
+                commands.write_message(AppExit::error());
+            }
+        },
+    );
+    assert!(app.run().is_success());
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct AlwaysDenyScreen;
+impl Screen for AlwaysDenyScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder.with_exit_guard(|| ExitDecision::Deny).add_systems(
+            ScreenSchedule::OnReady,
+            |mut commands: Commands| {
+                commands.trigger(switch_to_screen::<EmptyScreen>().forced());
+            },
+        );
+        builder
+    }
+}
+
+#[test]
+fn forced_switch() {
+    let mut app = get_test_app::<AlwaysDenyScreen>();
+    app.add_observer(|_: On<ScreenSwitchDenied>, mut commands: Commands| {
+        error!("Forced switch was denied.");
+        commands.write_message(AppExit::error());
+    });
+    app.add_systems(
+        on_screen_ready::<EmptyScreen>(),
+        |mut commands: Commands| {
+            commands.write_message(AppExit::Success);
+        },
+    );
+    assert!(app.run().is_success());
+}
@@ -0,0 +1,51 @@
+use crate::prelude::*;
+
+/// The failure reason seen in [ScreenSchedule::OnFailed].
+#[derive(Resource, Debug, Default)]
+struct SeenFailure(Option<String>);
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct FailingScreen;
+impl Screen for FailingScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(ScreenSchedule::OnLoad, |mut commands: Commands| {
+                commands.spawn(Name::new("Partial"));
+            })
+            .add_systems(ScreenSchedule::Loading, |mut commands: Commands| {
+                commands.trigger(fail_loading::<Self>("missing asset"));
+            })
+            .add_systems(
+                ScreenSchedule::OnFailed,
+                |data: ScreenInfoRef<Self>, mut seen: ResMut<SeenFailure>| {
+                    seen.0 = data.data().failure().map(ToOwned::to_owned);
+                },
+            );
+        builder
+    }
+}
+
+#[test]
+fn fallback_screen() {
+    let mut app = get_test_app::<FailingScreen>();
+    app.init_resource::<SeenFailure>();
+    app.insert_resource(FallbackScreen::new::<EmptyScreen>());
+    app.add_systems(
+        on_screen_ready::<EmptyScreen>(),
+        |seen: Res<SeenFailure>,
+         failing: ScreenInfoRef<FailingScreen>,
+         names: Query<&Name>,
+         mut commands: Commands| {
+            let ok = seen.0.as_deref() == Some("missing asset")
+                && failing.data().state() == ScreenState::Unloaded
+                && names.iter().all(|name| name.as_str() != "Partial");
+            if ok {
+                commands.write_message(AppExit::Success);
+            } else {
+                error!(?seen, state = ?failing.data().state(), "Bad failure handling.");
+                commands.write_message(AppExit::error());
+            }
+        },
+    );
+    assert!(app.run().is_success());
+}
@@ -0,0 +1,109 @@
+use crate::prelude::*;
+
+#[derive(Resource, Debug, Default)]
+struct FlowFrames(u32);
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct FlowStart;
+impl Screen for FlowStart {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder.add_systems(ScreenSchedule::OnReady, |mut commands: Commands| {
+            commands.trigger(switch_to_screen::<FlowEnd>());
+        });
+        builder
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct FlowEnd;
+impl Screen for FlowEnd {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(ScreenSchedule::OnReady, |mut commands: Commands| {
+                // Not in the flow, so this is rejected.
+                commands.trigger(switch_to_screen::<FlowStart>());
+                commands.trigger(push_screen::<EmptyScreen>());
+            })
+            .add_systems(
+                ScreenSchedule::Update,
+                |mut frames: ResMut<FlowFrames>,
+                 current: Res<CurrentScreen>,
+                 end: ScreenIdFor<Self>,
+                 mut commands: Commands| {
+                    frames.0 += 1;
+                    if current.get_id() != Some(*end) {
+                        commands.write_message(AppExit::error());
+                    } else if frames.0 > 10 {
+                        commands.write_message(AppExit::Success);
+                    }
+                },
+            );
+        builder
+    }
+}
+
+#[test]
+fn flow_rejects_illegal_switches() {
+    let mut app = get_test_app::<FlowStart>();
+    app.register_screen::<FlowEnd>();
+    app.init_resource::<FlowFrames>();
+    app.insert_resource(ScreenFlow::default().allow::<FlowStart, FlowEnd>());
+    assert!(app.run().is_success());
+}
+
+#[test]
+fn flow_from_ron() {
+    let flow = ScreenFlow::from_ron(
+        r#"{
+            "Splash": ["MainMenu"],
+            "MainMenu": ["World"],
+            "World": ["MainMenu", "Pause"],
+        }"#,
+    )
+    .unwrap();
+    let built = ScreenFlow::default()
+        .allow_by_name("Splash", "MainMenu")
+        .allow_by_name("MainMenu", "World")
+        .allow_by_name("World", "MainMenu")
+        .allow_by_name("World", "Pause");
+    assert_eq!(flow, built);
+    assert!(flow.is_allowed("World", "Pause"));
+    assert!(!flow.is_allowed("Pause", "World"));
+    assert_eq!(
+        flow.unreachable(["Splash"], ["Splash", "World", "Pause", "Credits"]),
+        vec!["Credits"]
+    );
+    assert!(flow.to_dot().contains("\"World\" -> \"Pause\";"));
+}
+
+#[test]
+fn flow_unreachable_with_cycles() {
+    let flow = ScreenFlow::default()
+        .allow_by_name("A", "B")
+        .allow_by_name("B", "C")
+        .allow_by_name("C", "A")
+        .allow_by_name("C", "C")
+        .allow_by_name("D", "A");
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let unreachable = flow
+            .unreachable(["A"], ["A", "B", "C", "D"])
+            .into_iter()
+            .map(str::to_owned)
+            .collect::<Vec<_>>();
+        tx.send(unreachable).unwrap();
+    });
+    let unreachable = rx
+        .recv_timeout(std::time::Duration::from_secs(5))
+        .expect("ScreenFlow::unreachable did not terminate");
+    assert_eq!(unreachable, vec!["D"]);
+}
+
+#[test]
+fn flow_asset_registered_after_asset_plugin() {
+    let mut app = App::new();
+    // AssetPlugin comes in with the test runner, after ScreenPlugin.
+    app.add_plugins((ScreenPlugin, TestRunnerPlugin::default()));
+    app.finish();
+    assert!(app.world().contains_resource::<Assets<ScreenFlow>>());
+}
@@ -0,0 +1,117 @@
+use crate::prelude::*;
+
+/// How many times [HistoryScreen] became Ready.
+#[derive(Resource, Debug, Default)]
+struct Visits(u32);
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct HistorySplashScreen;
+impl Screen for HistorySplashScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder.with_skip_history(true).add_systems(
+            ScreenSchedule::OnReady,
+            |mut commands: Commands| {
+                commands.trigger(SwitchToScreen::<HistoryScreen>::new(7));
+            },
+        );
+        builder
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct HistoryScreen;
+impl Screen for HistoryScreen {
+    type Params = u32;
+
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder.add_systems(ScreenSchedule::OnReady, Self::ready);
+        builder
+    }
+}
+impl HistoryScreen {
+    fn ready(
+        mut visits: ResMut<Visits>,
+        params: ScreenParams<Self>,
+        history: Res<ScreenHistory>,
+        id: ScreenIdFor<Self>,
+        mut commands: Commands,
+    ) {
+        visits.0 += 1;
+        let ids = history.iter().map(|entry| entry.id).collect::<Vec<_>>();
+        if visits.0 == 1 {
+            commands.trigger(switch_to_screen::<EmptyScreen>());
+        } else if *params == 7 && ids == [*id] {
+            // nothing left to go back to
+            commands.trigger(go_back());
+            commands.write_message(AppExit::Success);
+        } else {
+            error!(?ids, params = *params, "Bad history.");
+            commands.write_message(AppExit::error());
+        }
+    }
+}
+
+#[test]
+fn back_navigation() {
+    let mut app = get_test_app::<HistorySplashScreen>();
+    app.register_screen::<HistoryScreen>();
+    app.init_resource::<Visits>();
+    app.add_systems(
+        on_screen_ready::<EmptyScreen>(),
+        |history: Res<ScreenHistory>, mut commands: Commands| {
+            if history.len() == 2 {
+                commands.trigger(go_back());
+            } else {
+                error!(?history, "Expected HistoryScreen and EmptyScreen.");
+                commands.write_message(AppExit::error());
+            }
+        },
+    );
+    assert!(app.run().is_success());
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct GuardedHistoryScreen;
+impl Screen for GuardedHistoryScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder.with_exit_guard(|| ExitDecision::Deny).add_systems(
+            ScreenSchedule::OnReady,
+            |mut commands: Commands| {
+                commands.trigger(go_back());
+            },
+        );
+        builder
+    }
+}
+
+#[test]
+fn denied_back_navigation_keeps_history() {
+    let mut app = get_test_app::<EmptyScreen>();
+    app.register_screen::<GuardedHistoryScreen>();
+    app.init_resource::<Visits>();
+    app.add_systems(
+        on_screen_ready::<EmptyScreen>(),
+        |mut visits: ResMut<Visits>, history: Res<ScreenHistory>, mut commands: Commands| {
+            visits.0 += 1;
+            if visits.0 == 1 {
+                commands.trigger(switch_to_screen::<GuardedHistoryScreen>());
+            } else if history.len() == 1 {
+                commands.write_message(AppExit::Success);
+            } else {
+                error!(?history, "Expected only EmptyScreen.");
+                commands.write_message(AppExit::error());
+            }
+        },
+    );
+    app.add_observer(
+        |_: On<ScreenSwitchDenied>, history: Res<ScreenHistory>, mut commands: Commands| {
+            if history.len() == 2 {
+                commands.trigger(go_back().forced());
+            } else {
+                error!(?history, "Denied GoBack changed the history.");
+                commands.write_message(AppExit::error());
+            }
+        },
+    );
+    assert!(app.run().is_success());
+}
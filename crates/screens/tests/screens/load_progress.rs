@@ -0,0 +1,79 @@
+use std::{
+    future::poll_fn,
+    sync::atomic::{AtomicBool, Ordering},
+    task::Poll,
+};
+
+use crate::prelude::*;
+
+/// Holds back the asset server's load until [ScreenSchedule::Loading] has seen
+/// it, so the screen can't finish loading before then.
+static RELEASE: AtomicBool = AtomicBool::new(false);
+
+#[derive(Asset, TypePath, Debug)]
+struct TrackedAsset;
+
+/// Largest [ScreenLoadProgress::total] seen while loading.
+#[derive(Resource, Debug, Default)]
+struct SeenTotal(usize);
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct TrackedScreen;
+impl Screen for TrackedScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .with_skip_load(false)
+            .add_systems(ScreenSchedule::OnLoad, Self::track)
+            .add_systems(
+                ScreenSchedule::Loading,
+                |data: ScreenInfoRef<Self>, mut seen: ResMut<SeenTotal>| {
+                    seen.0 = seen.0.max(data.data().load_progress().total);
+                    if seen.0 == 2 {
+                        RELEASE.store(true, Ordering::Release);
+                    }
+                },
+            )
+            .add_systems(ScreenSchedule::OnReady, Self::ready);
+        builder
+    }
+}
+impl TrackedScreen {
+    fn track(
+        mut data: ScreenInfoMut<Self>,
+        asset_server: Res<AssetServer>,
+        mut assets: ResMut<Assets<TrackedAsset>>,
+    ) {
+        // One asset loaded through the asset server, and one which is not.
+        let loaded = asset_server.add_async(async {
+            poll_fn(|cx| {
+                if RELEASE.load(Ordering::Acquire) {
+                    Poll::Ready(())
+                } else {
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+            })
+            .await;
+            Ok::<_, std::io::Error>(TrackedAsset)
+        });
+        data.track_assets([loaded.untyped(), assets.add(TrackedAsset).untyped()]);
+    }
+
+    fn ready(data: ScreenInfoRef<Self>, seen: Res<SeenTotal>, mut commands: Commands) {
+        let progress = data.data().load_progress();
+        if seen.0 == 2 && progress == (ScreenLoadProgress { done: 2, total: 2 }) {
+            commands.write_message(AppExit::Success);
+        } else {
+            error!(?progress, ?seen, "Bad load progress.");
+            commands.write_message(AppExit::error());
+        }
+    }
+}
+
+#[test]
+fn tracked_assets() {
+    let mut app = get_test_app::<TrackedScreen>();
+    app.init_asset::<TrackedAsset>();
+    app.init_resource::<SeenTotal>();
+    assert!(app.run().is_success());
+}
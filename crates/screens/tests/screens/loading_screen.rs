@@ -0,0 +1,74 @@
+use crate::prelude::*;
+
+/// How many frames [SlowScreen] spent loading while [LoadingScreen] was Ready.
+#[derive(Resource, Debug, Default)]
+struct FramesShown(u32);
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct SlowScreen;
+impl Screen for SlowScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .with_loading_screen::<LoadingScreen>()
+            .add_systems(ScreenSchedule::Loading, Self::loading);
+        builder
+    }
+}
+impl SlowScreen {
+    fn loading(
+        mut count: Local<u32>,
+        mut data: ScreenInfoMut<Self>,
+        loading_screen: ScreenInfoRef<LoadingScreen>,
+        mut shown: ResMut<FramesShown>,
+    ) {
+        if loading_screen.data().state() == ScreenState::Ready {
+            shown.0 += 1;
+        }
+        *count += 1;
+        if *count == 10 {
+            data.finish_loading();
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct LoadingScreen;
+impl Screen for LoadingScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(ScreenSchedule::OnReady, |mut commands: Commands| {
+                commands.spawn(Name::new("Spinner"));
+            })
+            .add_systems(ScreenSchedule::OnUnloaded, Self::unloaded);
+        builder
+    }
+}
+impl LoadingScreen {
+    fn unloaded(
+        slow: ScreenInfoRef<SlowScreen>,
+        shown: Res<FramesShown>,
+        current_screen: Res<CurrentScreen>,
+        id: ScreenIdFor<SlowScreen>,
+        spinners: Query<&Name>,
+        mut commands: Commands,
+    ) {
+        let ok = slow.data().state() == ScreenState::Ready
+            && current_screen.get_id() == Some(*id)
+            && shown.0 > 1
+            && spinners.iter().all(|name| name.as_str() != "Spinner");
+        if ok {
+            commands.write_message(AppExit::Success);
+        } else {
+            error!(?shown, state = ?slow.data().state(), "Bad loading screen.");
+            commands.write_message(AppExit::error());
+        }
+    }
+}
+
+#[test]
+fn loading_screen() {
+    let mut app = get_test_app::<SlowScreen>();
+    app.register_screen::<LoadingScreen>();
+    app.init_resource::<FramesShown>();
+    assert!(app.run().is_success());
+}
@@ -0,0 +1,62 @@
+use crate::prelude::*;
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct MeasuredScreen;
+impl Screen for MeasuredScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .with_skip_load(false)
+            .add_systems(
+                ScreenSchedule::Loading,
+                |mut frames: Local<u32>, mut commands: Commands| {
+                    *frames += 1;
+                    if *frames == 3 {
+                        commands.trigger(finish_loading::<Self>());
+                    }
+                },
+            )
+            .add_systems(ScreenSchedule::OnReady, |mut commands: Commands| {
+                commands.spawn_batch([Name::new("A"), Name::new("B")]);
+                commands.trigger(switch_to_screen::<MetricsCheckScreen>());
+            });
+        builder
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct MetricsCheckScreen;
+impl Screen for MetricsCheckScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder.add_systems(ScreenSchedule::Update, Self::check);
+        builder
+    }
+}
+impl MetricsCheckScreen {
+    fn check(metrics: Res<ScreenMetrics>, mut commands: Commands) {
+        let Some(stats) = metrics.get::<MeasuredScreen>() else {
+            return;
+        };
+        if *stats.state() != ScreenState::Unloaded {
+            return;
+        }
+        if metrics.loaded_within::<MeasuredScreen>(5)
+            && !metrics.loaded_within::<MeasuredScreen>(1)
+            && stats.loads == 1
+            && stats.failures == 0
+            && stats.last_despawned >= 2
+            && stats.transitions >= 4
+        {
+            commands.write_message(AppExit::Success);
+        } else {
+            error!(?stats, "Unexpected screen metrics.");
+            commands.write_message(AppExit::error());
+        }
+    }
+}
+
+#[test]
+fn metrics() {
+    let mut app = get_test_app::<MeasuredScreen>();
+    app.register_screen::<MetricsCheckScreen>();
+    assert!(app.run().is_success());
+}
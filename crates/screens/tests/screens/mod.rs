@@ -1,8 +1,28 @@
 mod conditionals;
+mod derive;
+mod dynamic;
 mod empty;
 mod entity_scope;
+mod exit_guard;
+mod failed;
+mod flow;
+mod history;
 mod lifecycle;
+mod load_progress;
 mod load_strategy;
+mod loading_screen;
+mod metrics;
+mod params;
+mod pause;
+mod persist;
+mod preload;
+mod reregister;
+mod scene;
+mod scoped_observer;
+mod scoped_resource;
+mod stack;
+mod states;
+mod transition;
 
 pub mod prelude {
     pub use super::empty::*;
@@ -0,0 +1,73 @@
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+struct LevelParams {
+    level: u32,
+}
+
+/// Levels seen by [ParamsScreen] in [ScreenSchedule::OnLoad].
+#[derive(Resource, Debug, Default)]
+struct SeenLevels(Vec<u32>);
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct ParamsScreen;
+impl Screen for ParamsScreen {
+    type Params = LevelParams;
+
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(
+                ScreenSchedule::OnLoad,
+                |params: ScreenParams<Self>, mut seen: ResMut<SeenLevels>| {
+                    seen.0.push(params.level);
+                },
+            )
+            .add_systems(ScreenSchedule::Loading, |mut commands: Commands| {
+                commands.trigger(finish_loading::<Self>());
+            })
+            .add_systems(ScreenSchedule::OnReady, Self::ready);
+        builder
+    }
+}
+impl ParamsScreen {
+    fn ready(mut commands: Commands, seen: Res<SeenLevels>) {
+        if seen.0.len() < 3 {
+            commands.trigger(switch_to_screen::<EmptyScreen>());
+        } else if seen.0 == [0, 12, 3] {
+            commands.write_message(AppExit::Success);
+        } else {
+            error!(?seen, "Bad params.");
+            commands.write_message(AppExit::error());
+        }
+    }
+}
+
+fn empty_ready(
+    mut commands: Commands,
+    seen: Res<SeenLevels>,
+    screens: Screens,
+    type_registry: Res<AppTypeRegistry>,
+) {
+    if seen.0.len() == 1 {
+        commands.trigger(SwitchToScreen::<ParamsScreen>::new(LevelParams {
+            level: 12,
+        }));
+    } else {
+        let screen = screens.get::<ParamsScreen>().unwrap();
+        // invalid params are ignored
+        let invalid = SwitchToScreenMsg::new(screen.screen_id()).with_params(Box::new(12u32));
+        let params = screen
+            .parse_params(&type_registry.read(), "(level: 3)")
+            .unwrap();
+        commands.write_message(SwitchToScreenMsg::new(screen.screen_id()).with_params(params));
+        commands.write_message(invalid);
+    }
+}
+
+#[test]
+fn test_screen_params() {
+    let mut app = get_test_app::<ParamsScreen>();
+    app.init_resource::<SeenLevels>();
+    app.add_systems(on_screen_ready::<EmptyScreen>(), empty_ready);
+    assert!(app.run().is_success());
+}
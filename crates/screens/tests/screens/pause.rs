@@ -0,0 +1,110 @@
+use crate::prelude::*;
+use std::time::Duration;
+
+#[derive(Resource, Debug, Default)]
+struct PauseStatus {
+    updates: u32,
+    paused_frames: u32,
+    updates_at_pause: Option<u32>,
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct PausedScreen;
+impl Screen for PausedScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(ScreenSchedule::Update, Self::update)
+            .add_systems(ScreenSchedule::Paused, Self::paused);
+        builder
+    }
+}
+impl PausedScreen {
+    fn update(mut status: ResMut<PauseStatus>, mut commands: Commands) {
+        status.updates += 1;
+        match status.updates_at_pause {
+            None if status.updates == 3 => {
+                status.updates_at_pause = Some(status.updates);
+                commands.trigger(pause_screen::<Self>());
+            }
+            Some(updates) if updates + 1 == status.updates => {
+                if status.paused_frames == 3 {
+                    commands.write_message(AppExit::Success);
+                } else {
+                    error!(?status, "Screen was not paused for the expected frames.");
+                    commands.write_message(AppExit::error());
+                }
+            }
+            _ => {}
+        }
+    }
+    fn paused(
+        mut status: ResMut<PauseStatus>,
+        data: ScreenInfoRef<Self>,
+        time: Res<Time>,
+        mut commands: Commands,
+    ) {
+        if status.updates_at_pause != Some(status.updates) {
+            error!(?status, "Update ran while paused.");
+            commands.write_message(AppExit::error());
+            return;
+        }
+        if data.data().clock().delta() != Duration::ZERO || time.delta() != Duration::ZERO {
+            error!("Clock advanced while paused.");
+            commands.write_message(AppExit::error());
+            return;
+        }
+        status.paused_frames += 1;
+        if status.paused_frames == 3 {
+            commands.trigger(resume_screen::<Self>());
+        }
+    }
+}
+
+#[test]
+fn pause() {
+    let mut app = get_test_app::<PausedScreen>();
+    app.init_resource::<PauseStatus>();
+    assert!(app.run().is_success());
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct SlowScreen;
+impl Screen for SlowScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(ScreenSchedule::OnReady, |mut data: ScreenInfoMut<Self>| {
+                data.set_relative_speed(0.5);
+            })
+            .add_systems(ScreenSchedule::Update, Self::update);
+        builder
+    }
+}
+impl SlowScreen {
+    fn update(
+        data: ScreenInfoRef<Self>,
+        time: Res<Time>,
+        virtual_time: Res<Time<Virtual>>,
+        mut frames: Local<u32>,
+        mut commands: Commands,
+    ) {
+        // On the first Ready frame, Update runs before OnReady sets the speed.
+        if virtual_time.delta() == Duration::ZERO || data.data().clock().relative_speed() != 0.5 {
+            return;
+        }
+        if time.delta() != virtual_time.delta().mul_f64(0.5) {
+            error!(?time, ?virtual_time, "Screen time was not scaled.");
+            commands.write_message(AppExit::error());
+            return;
+        }
+        *frames += 1;
+        if *frames == 3 {
+            commands.write_message(AppExit::Success);
+        }
+    }
+}
+
+#[test]
+fn slow_motion() {
+    let mut app = get_test_app::<SlowScreen>();
+    assert!(app.run().is_success());
+}
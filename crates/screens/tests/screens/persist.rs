@@ -0,0 +1,90 @@
+use std::path::PathBuf;
+
+use crate::prelude::*;
+
+#[derive(Debug, Clone, Default, PartialEq, Reflect)]
+struct SlotParams {
+    slot: u32,
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct SavedScreen;
+impl Screen for SavedScreen {
+    type Params = SlotParams;
+
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder.add_systems(
+            ScreenSchedule::OnReady,
+            |params: ScreenParams<Self>, mut commands: Commands| {
+                if params.slot == 7 {
+                    commands.write_message(AppExit::Success);
+                } else {
+                    error!(?params.slot, "Params were not restored.");
+                    commands.write_message(AppExit::error());
+                }
+            },
+        );
+        builder
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct FreshScreen;
+impl Screen for FreshScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder.add_systems(ScreenSchedule::OnReady, |mut commands: Commands| {
+            commands.write_message(AppExit::Success);
+        });
+        builder
+    }
+}
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("q_screens_{name}_{}.ron", std::process::id()))
+}
+
+fn app_with_saved(path: &PathBuf, saved: &PersistedScreen) -> App {
+    ScreenPersistence {
+        path: path.clone(),
+        resume: true,
+    }
+    .save(saved)
+    .unwrap();
+    let mut app = get_test_app::<FreshScreen>();
+    app.register_screen::<SavedScreen>();
+    app.add_plugins(ScreenPersistencePlugin::new(path).with_resume(true));
+    app
+}
+
+#[test]
+fn resume_last_screen() {
+    let path = temp_path("resume");
+    let saved = PersistedScreen {
+        name: SavedScreen::name(),
+        params: Some("(slot: 7)".into()),
+    };
+    let mut app = app_with_saved(&path, &saved);
+    let success = app.run().is_success();
+    let resaved = ScreenPersistence {
+        path: path.clone(),
+        resume: true,
+    }
+    .load();
+    std::fs::remove_file(&path).ok();
+    assert!(success);
+    // the restored screen is saved again
+    assert_eq!(resaved.unwrap().map(|s| s.name), Some(saved.name));
+}
+
+#[test]
+fn resume_missing_screen() {
+    let path = temp_path("missing");
+    let saved = PersistedScreen {
+        name: "RemovedScreen".into(),
+        params: None,
+    };
+    let mut app = app_with_saved(&path, &saved);
+    let success = app.run().is_success();
+    std::fs::remove_file(&path).ok();
+    assert!(success);
+}
@@ -0,0 +1,133 @@
+use crate::prelude::*;
+
+#[derive(Resource, Debug, Default)]
+struct PreloadStatus {
+    loading_frames: u32,
+    frames_at_switch: Option<u32>,
+    updated_early: bool,
+    ready_early: bool,
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct PreloadTarget;
+impl Screen for PreloadTarget {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(
+                ScreenSchedule::Loading,
+                |mut status: ResMut<PreloadStatus>, mut commands: Commands| {
+                    status.loading_frames += 1;
+                    if status.loading_frames == 3 {
+                        commands.trigger(finish_loading::<Self>());
+                    }
+                },
+            )
+            .add_systems(
+                ScreenSchedule::Update,
+                |mut status: ResMut<PreloadStatus>| {
+                    if status.frames_at_switch.is_none() {
+                        status.updated_early = true;
+                    }
+                },
+            )
+            .add_systems(ScreenSchedule::OnReady, Self::ready);
+        builder
+    }
+}
+impl PreloadTarget {
+    fn ready(status: Res<PreloadStatus>, mut commands: Commands) {
+        if status.frames_at_switch == Some(status.loading_frames)
+            && !status.updated_early
+            && !status.ready_early
+        {
+            commands.write_message(AppExit::Success);
+        } else {
+            error!(?status, "Preloaded screen did not go straight to Ready.");
+            commands.write_message(AppExit::error());
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct PreloadHost;
+impl Screen for PreloadHost {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(ScreenSchedule::OnReady, |mut commands: Commands| {
+                commands.trigger(preload_screen::<PreloadTarget>());
+            })
+            .add_systems(ScreenSchedule::Update, Self::update);
+        builder
+    }
+}
+impl PreloadHost {
+    fn update(
+        target: ScreenInfoRef<PreloadTarget>,
+        mut status: ResMut<PreloadStatus>,
+        mut commands: Commands,
+    ) {
+        let target = target.data();
+        if status.frames_at_switch.is_some() || !target.preloading() {
+            return;
+        }
+        if target.state() == ScreenState::Ready {
+            status.frames_at_switch = Some(status.loading_frames);
+            commands.trigger(switch_to_screen::<PreloadTarget>());
+        }
+    }
+}
+
+#[test]
+fn preload() {
+    let mut app = get_test_app::<PreloadHost>();
+    app.register_screen::<PreloadTarget>();
+    app.init_resource::<PreloadStatus>();
+    app.add_systems(
+        on_screen_ready::<PreloadTarget>(),
+        |mut status: ResMut<PreloadStatus>| {
+            // must not run while preloading
+            status.ready_early |= status.frames_at_switch.is_none();
+        },
+    );
+    assert!(app.run().is_success());
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct CancelHost;
+impl Screen for CancelHost {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(ScreenSchedule::OnReady, |mut commands: Commands| {
+                commands.trigger(preload_screen::<PreloadTarget>());
+            })
+            .add_systems(ScreenSchedule::Update, Self::update);
+        builder
+    }
+}
+impl CancelHost {
+    fn update(
+        host: ScreenInfoRef<Self>,
+        target: ScreenInfoRef<PreloadTarget>,
+        status: Res<PreloadStatus>,
+        mut commands: Commands,
+    ) {
+        let target = target.data();
+        if target.preloading() && status.loading_frames > 0 {
+            commands.trigger(cancel_preload::<PreloadTarget>());
+        } else if status.loading_frames > 0 && target.state() == ScreenState::Unloaded {
+            if host.data().state() == ScreenState::Ready && !target.preloading() {
+                commands.write_message(AppExit::Success);
+            } else {
+                commands.write_message(AppExit::error());
+            }
+        }
+    }
+}
+
+#[test]
+fn cancelled_preload() {
+    let mut app = get_test_app::<CancelHost>();
+    app.register_screen::<PreloadTarget>();
+    app.init_resource::<PreloadStatus>();
+    assert!(app.run().is_success());
+}
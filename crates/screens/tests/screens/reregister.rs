@@ -0,0 +1,189 @@
+use crate::prelude::*;
+
+#[derive(Resource, Debug, Default)]
+struct Version(u32);
+
+fn hot_screen(
+    version: u32,
+) -> impl FnOnce(ScreenScopeBuilder<DynamicScreen>) -> ScreenScopeBuilder<DynamicScreen> {
+    move |mut builder| {
+        builder.add_systems(ScreenSchedule::OnReady, move |mut v: ResMut<Version>| {
+            v.0 = version;
+        });
+        builder
+    }
+}
+
+fn get_manual_app() -> App {
+    let mut app = App::new();
+    app.add_plugins((TestRunnerPlugin::default(), ScreenPlugin));
+    app.init_resource::<Version>();
+    app
+}
+
+fn update(app: &mut App) {
+    for _ in 0..10 {
+        app.update();
+    }
+}
+
+#[test]
+fn reregister_reloads_current_screen() {
+    let mut app = get_manual_app();
+    app.register_dynamic_screen("Hot", hot_screen(1));
+    app.insert_resource(InitialScreen::from_name("Hot".into()));
+    app.finish();
+    app.cleanup();
+    update(&mut app);
+    assert_eq!(app.world().resource::<Version>().0, 1);
+    let id = app.world().resource::<CurrentScreen>().get_id();
+    assert!(id.is_some());
+
+    app.reregister_dynamic_screen("Hot", hot_screen(2));
+    update(&mut app);
+    assert_eq!(app.world().resource::<Version>().0, 2);
+    assert_eq!(app.world().resource::<CurrentScreen>().get_id(), id);
+    let info = app
+        .world()
+        .resource::<ScreenData>()
+        .get(id.unwrap())
+        .unwrap();
+    assert_eq!(info.state(), ScreenState::Ready);
+}
+
+#[test]
+fn unregister_screen() {
+    let mut app = get_manual_app();
+    app.register_screen::<EmptyScreen>();
+    app.insert_resource(InitialScreen::new::<EmptyScreen>());
+    app.finish();
+    app.cleanup();
+    update(&mut app);
+    let id = app
+        .world()
+        .resource::<ScreenRegistry>()
+        .get(&std::any::TypeId::of::<EmptyScreen>())
+        .unwrap();
+    let owned = app.world_mut().spawn(ScreenOwner(id)).id();
+
+    app.unregister_screen::<EmptyScreen>();
+    let world = app.world();
+    assert!(world.get_entity(owned).is_err());
+    assert!(world.resource::<ScreenData>().get(id).is_err());
+    assert!(
+        world
+            .resource::<ScreenRegistry>()
+            .get(&std::any::TypeId::of::<EmptyScreen>())
+            .is_err()
+    );
+    assert_eq!(world.resource::<CurrentScreen>().get_id(), None);
+    assert!(
+        !world
+            .resource::<Schedules>()
+            .contains(on_screen_ready::<EmptyScreen>())
+    );
+
+    // Can be registered again, and keeps its id.
+    app.register_screen::<EmptyScreen>();
+    assert!(app.world().resource::<ScreenData>().get(id).is_ok());
+}
+
+#[derive(Resource, Debug, Default, PartialEq, Eq)]
+struct Unloads {
+    unload: u32,
+    unloaded: u32,
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct TopScreen;
+impl Screen for TopScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(ScreenSchedule::OnUnload, |mut unloads: ResMut<Unloads>| {
+                unloads.unload += 1;
+            })
+            .add_systems(
+                ScreenSchedule::OnUnloaded,
+                |mut unloads: ResMut<Unloads>| {
+                    unloads.unloaded += 1;
+                },
+            );
+        builder
+    }
+}
+
+#[test]
+fn unregister_top_of_stack() {
+    let mut app = get_manual_app();
+    app.init_resource::<Unloads>();
+    app.register_screen::<EmptyScreen>();
+    app.register_screen::<TopScreen>();
+    app.insert_resource(InitialScreen::new::<EmptyScreen>());
+    app.finish();
+    app.cleanup();
+    update(&mut app);
+    app.world_mut().trigger(push_screen::<TopScreen>());
+    update(&mut app);
+    let below = app
+        .world()
+        .resource::<ScreenRegistry>()
+        .get(&std::any::TypeId::of::<EmptyScreen>())
+        .unwrap();
+    assert!(
+        app.world()
+            .resource::<ScreenData>()
+            .get(below)
+            .unwrap()
+            .covered()
+    );
+
+    app.unregister_screen::<TopScreen>();
+    let world = app.world();
+    assert_eq!(world.resource::<CurrentScreen>().get_id(), Some(below));
+    assert_eq!(**world.resource::<ScreenStack>(), [below]);
+    assert!(!world.resource::<ScreenData>().get(below).unwrap().covered());
+    assert_eq!(
+        *world.resource::<Unloads>(),
+        Unloads {
+            unload: 1,
+            unloaded: 1
+        }
+    );
+}
+
+/// How many times a system outside of [TopScreen]'s scope saw it.
+#[derive(Resource, Debug, Default)]
+struct Sightings(u32);
+
+#[test]
+fn typed_params_across_unregister() {
+    let mut app = get_manual_app();
+    app.init_resource::<Unloads>();
+    app.init_resource::<Sightings>();
+    app.register_screen::<EmptyScreen>();
+    app.register_screen::<TopScreen>();
+    app.insert_resource(InitialScreen::new::<EmptyScreen>());
+    // Not part of TopScreen's scope, so it outlives the registration.
+    app.add_systems(
+        Update,
+        |_: ScreenIdFor<TopScreen>,
+         _: ScreenInfoRef<TopScreen>,
+         _: ScreenParams<TopScreen>,
+         mut sightings: ResMut<Sightings>| {
+            sightings.0 += 1;
+        },
+    );
+    app.finish();
+    app.cleanup();
+    update(&mut app);
+    assert_eq!(app.world().resource::<Sightings>().0, 10);
+
+    // Skipped rather than panicking while TopScreen is gone.
+    app.unregister_screen::<TopScreen>();
+    update(&mut app);
+    assert_eq!(app.world().resource::<Sightings>().0, 10);
+
+    app.register_screen::<TopScreen>();
+    update(&mut app);
+    assert_eq!(app.world().resource::<Sightings>().0, 20);
+}
@@ -0,0 +1,91 @@
+use std::path::Path;
+
+use crate::prelude::*;
+use bevy::{
+    asset::io::{
+        AssetSourceBuilder,
+        memory::{Dir, MemoryAssetReader},
+    },
+    scene::ScenePlugin,
+};
+
+#[derive(Component, Reflect, Debug, Default, Clone, PartialEq)]
+#[reflect(Component)]
+struct SceneMarker(u32);
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct SceneScreen;
+impl Screen for SceneScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .with_scene("memory://scenes/test.scn.ron")
+            .add_systems(ScreenSchedule::Update, Self::update);
+        builder
+    }
+}
+impl SceneScreen {
+    fn update(
+        markers: Query<(&SceneMarker, &ScreenOwner, &ChildOf)>,
+        roots: Query<&ScreenOwner, With<ScreenSceneRoot>>,
+        id: ScreenIdFor<Self>,
+        mut commands: Commands,
+    ) {
+        let Ok((marker, owner, child_of)) = markers.single() else {
+            // still spawning
+            return;
+        };
+        let root_owner = roots.get(child_of.parent()).ok();
+        if marker.0 == 7 && owner.0 == *id && root_owner.is_some_and(|o| o.0 == *id) {
+            commands.trigger(switch_to_screen::<EmptyScreen>());
+        } else {
+            error!(?marker, ?owner, "Scene was not spawned under the screen.");
+            commands.write_message(AppExit::error());
+        }
+    }
+}
+
+/// Serializes a scene with a single [SceneMarker].
+fn scene_ron(registry: &AppTypeRegistry) -> String {
+    let mut world = World::new();
+    world.insert_resource(registry.clone());
+    world.spawn(SceneMarker(7));
+    let scene = DynamicScene::from_world(&world);
+    scene.serialize(&registry.read()).unwrap()
+}
+
+#[test]
+fn scene() {
+    let dir = Dir::default();
+    let mut app = App::new();
+    let reader_dir = dir.clone();
+    app.register_asset_source(
+        "memory",
+        AssetSourceBuilder::new(move || {
+            Box::new(MemoryAssetReader {
+                root: reader_dir.clone(),
+            })
+        }),
+    );
+    app.add_plugins((TestRunnerPlugin::default(), ScenePlugin, ScreenPlugin));
+    app.register_type::<SceneMarker>();
+    app.register_screen::<SceneScreen>();
+    app.register_screen::<EmptyScreen>();
+    app.insert_resource(InitialScreen::new::<SceneScreen>());
+    let ron = scene_ron(app.world().resource::<AppTypeRegistry>());
+    dir.insert_asset_text(Path::new("scenes/test.scn.ron"), &ron);
+
+    app.add_systems(
+        on_screen_ready::<EmptyScreen>(),
+        |markers: Query<(), With<SceneMarker>>,
+         roots: Query<(), With<ScreenSceneRoot>>,
+         mut commands: Commands| {
+            if markers.is_empty() && roots.is_empty() {
+                commands.write_message(AppExit::Success);
+            } else {
+                error!("Scene was not despawned.");
+                commands.write_message(AppExit::error());
+            }
+        },
+    );
+    assert!(app.run().is_success());
+}
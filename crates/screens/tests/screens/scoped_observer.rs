@@ -0,0 +1,55 @@
+use crate::prelude::*;
+
+#[derive(Event, Debug, Clone, Copy)]
+struct Ping;
+
+#[derive(Resource, Debug, Default)]
+struct Pings(u32);
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct ObserverScreen;
+impl Screen for ObserverScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_observer(|_: On<Ping>, mut pings: ResMut<Pings>| {
+                pings.0 += 1;
+            })
+            .add_systems(ScreenSchedule::OnReady, |mut commands: Commands| {
+                commands.trigger(Ping);
+                commands.trigger(switch_to_screen::<EmptyScreen>());
+            });
+        builder
+    }
+}
+
+#[test]
+fn scoped_observer() {
+    let mut app = get_test_app::<ObserverScreen>();
+    app.init_resource::<Pings>();
+    app.add_systems(
+        on_screen_ready::<EmptyScreen>(),
+        |mut commands: Commands| {
+            // should not be observed
+            commands.trigger(Ping);
+        },
+    );
+    app.add_systems(
+        PostUpdate,
+        |pings: Res<Pings>,
+         observers: Query<(), With<ScreenObserver>>,
+         empty: ScreenInfoRef<EmptyScreen>,
+         mut commands: Commands| {
+            // wait until OnReady has run
+            if empty.data().state() != ScreenState::Ready || empty.data().needs_update() {
+                return;
+            }
+            if pings.0 == 1 && observers.is_empty() {
+                commands.write_message(AppExit::Success);
+            } else {
+                error!(pings = pings.0, "Scoped observer was not removed.");
+                commands.write_message(AppExit::error());
+            }
+        },
+    );
+    assert!(app.run().is_success());
+}
@@ -0,0 +1,74 @@
+use crate::prelude::*;
+
+#[derive(Resource, Debug, Default)]
+struct Score(u32);
+
+#[derive(Resource, Debug, Clone, PartialEq)]
+struct Seed(u64);
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct ScopedResourceScreen;
+impl Screen for ScopedResourceScreen {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .init_resource::<Score>()
+            .insert_resource(Seed(42))
+            .add_systems(ScreenSchedule::FixedUpdate, |seed: Res<Seed>| {
+                assert_eq!(seed.0, 42);
+            })
+            .add_systems(
+                ScreenSchedule::Update,
+                |mut score: ScreenResMut<Self, Score>, mut commands: Commands| {
+                    let score = score.get_mut().unwrap();
+                    score.0 += 1;
+                    if score.0 == 3 {
+                        commands.trigger(switch_to_screen::<EmptyScreen>());
+                    }
+                },
+            );
+        builder
+    }
+}
+
+#[test]
+fn scoped_resource() {
+    let mut app = get_test_app::<ScopedResourceScreen>();
+    app.add_systems(
+        on_screen_ready::<EmptyScreen>(),
+        |score: ScreenRes<ScopedResourceScreen, Score>,
+         seed: Option<Res<Seed>>,
+         mut commands: Commands| {
+            match score.get() {
+                Err(ScreenError::ScopedResourceUnavailable(..)) if seed.is_none() => {
+                    commands.write_message(AppExit::Success);
+                }
+                res => {
+                    error!(?res, "Scoped resources were not removed.");
+                    commands.write_message(AppExit::error());
+                }
+            }
+        },
+    );
+    assert!(app.run().is_success());
+}
+
+#[test]
+fn scoped_resource_of_unloaded_screen() {
+    let mut app = get_test_app::<EmptyScreen>();
+    app.register_screen::<ScopedResourceScreen>();
+    // Not ScopedResourceScreen's, which is never loaded.
+    app.init_resource::<Score>();
+    app.add_systems(
+        on_screen_ready::<EmptyScreen>(),
+        |score: ScreenRes<ScopedResourceScreen, Score>, mut commands: Commands| match score.get() {
+            Err(ScreenError::ScopedResourceUnavailable(..)) => {
+                commands.write_message(AppExit::Success);
+            }
+            res => {
+                error!(?res, "Got a resource from an unloaded screen.");
+                commands.write_message(AppExit::error());
+            }
+        },
+    );
+    assert!(app.run().is_success());
+}
@@ -0,0 +1,160 @@
+use crate::prelude::*;
+
+/// How many times the base screen's [ScreenSchedule::Update] has run.
+#[derive(Resource, Debug, Default)]
+struct BaseTicks(u32);
+
+/// How many times the base screen's [ScreenSchedule::Paused] has run.
+#[derive(Resource, Debug, Default)]
+struct BasePausedTicks(u32);
+
+/// [BaseTicks] when the overlay finished loading.
+#[derive(Resource, Debug, Default)]
+struct TicksAtCover(u32);
+
+const RUN: u8 = 0;
+const PAUSE: u8 = 1;
+const RENDER_ONLY: u8 = 2;
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct StackBaseScreen<const POLICY: u8>;
+impl<const POLICY: u8> Screen for StackBaseScreen<POLICY> {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .with_covered_policy(Self::policy())
+            .add_systems(ScreenSchedule::Update, |mut ticks: ResMut<BaseTicks>| {
+                ticks.0 += 1;
+            })
+            .add_systems(
+                ScreenSchedule::Paused,
+                |mut ticks: ResMut<BasePausedTicks>| {
+                    ticks.0 += 1;
+                },
+            )
+            .add_systems(ScreenSchedule::OnReady, |mut commands: Commands| {
+                commands.spawn((Name::new("Base"), Visibility::Visible));
+                commands.trigger(push_screen::<StackOverlayScreen<POLICY>>());
+            });
+        builder
+    }
+}
+impl<const POLICY: u8> StackBaseScreen<POLICY> {
+    fn policy() -> CoveredPolicy {
+        match POLICY {
+            RUN => CoveredPolicy::Run,
+            PAUSE => CoveredPolicy::Pause,
+            _ => CoveredPolicy::RenderOnly,
+        }
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct StackOverlayScreen<const POLICY: u8>;
+impl<const POLICY: u8> Screen for StackOverlayScreen<POLICY> {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .add_systems(
+                ScreenSchedule::OnReady,
+                |ticks: Res<BaseTicks>, mut at_cover: ResMut<TicksAtCover>| {
+                    at_cover.0 = ticks.0;
+                },
+            )
+            .add_systems(ScreenSchedule::Update, Self::update)
+            .add_systems(ScreenSchedule::OnUnloaded, Self::unloaded);
+        builder
+    }
+}
+impl<const POLICY: u8> StackOverlayScreen<POLICY> {
+    fn update(
+        mut count: Local<u32>,
+        mut commands: Commands,
+        ticks: Res<BaseTicks>,
+        paused_ticks: Res<BasePausedTicks>,
+        at_cover: Res<TicksAtCover>,
+        screens: Screens,
+        stack: Res<ScreenStack>,
+        current_screen: Res<CurrentScreen>,
+        visibility: Query<(&Name, &Visibility)>,
+    ) {
+        *count += 1;
+        if *count < 10 {
+            return;
+        }
+        let base = screens.get::<StackBaseScreen<POLICY>>().unwrap();
+        let this = screens.get::<Self>().unwrap();
+        let base_visibility = visibility
+            .iter()
+            .find_map(|(name, vis)| (name.as_str() == "Base").then_some(*vis));
+        let mut ok = base.state() == ScreenState::Ready && base.covered();
+        ok = ok && stack.top() == Some(this.screen_id());
+        ok = ok && current_screen.get_id() == Some(this.screen_id());
+        // No policy touches the covered screen's entities.
+        ok = ok && base_visibility == Some(Visibility::Visible);
+        ok = ok
+            && match POLICY {
+                RUN => ticks.0 > at_cover.0 && paused_ticks.0 == 0,
+                PAUSE => ticks.0 == at_cover.0 && paused_ticks.0 > 0,
+                _ => ticks.0 == at_cover.0 && paused_ticks.0 == 0,
+            };
+        if ok {
+            commands.trigger(PopScreen);
+        } else {
+            error!(
+                ?ticks,
+                ?paused_ticks,
+                ?at_cover,
+                ?base_visibility,
+                "Bad covered state."
+            );
+            commands.write_message(AppExit::error());
+        }
+    }
+
+    fn unloaded(
+        mut commands: Commands,
+        screens: Screens,
+        stack: Res<ScreenStack>,
+        current_screen: Res<CurrentScreen>,
+        visibility: Query<(&Name, &Visibility)>,
+    ) {
+        let base = screens.get::<StackBaseScreen<POLICY>>().unwrap();
+        let ok = base.state() == ScreenState::Ready
+            && !base.covered()
+            && stack.top() == Some(base.screen_id())
+            && stack.len() == 1
+            && current_screen.get_id() == Some(base.screen_id())
+            && visibility
+                .iter()
+                .any(|(name, vis)| name.as_str() == "Base" && *vis == Visibility::Visible);
+        if ok {
+            commands.write_message(AppExit::Success);
+        } else {
+            error!("Base screen was not uncovered.");
+            commands.write_message(AppExit::error());
+        }
+    }
+}
+
+fn run_stack_test<const POLICY: u8>() {
+    let mut app = get_test_app::<StackBaseScreen<POLICY>>();
+    app.register_screen::<StackOverlayScreen<POLICY>>();
+    app.init_resource::<BaseTicks>();
+    app.init_resource::<BasePausedTicks>();
+    app.init_resource::<TicksAtCover>();
+    assert!(app.run().is_success());
+}
+
+#[test]
+fn covered_run() {
+    run_stack_test::<RUN>();
+}
+
+#[test]
+fn covered_pause() {
+    run_stack_test::<PAUSE>();
+}
+
+#[test]
+fn covered_render_only() {
+    run_stack_test::<RENDER_ONLY>();
+}
@@ -0,0 +1,94 @@
+use crate::prelude::*;
+
+#[derive(Resource, Debug, Default)]
+struct EnteredReady(bool);
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct StateScreen;
+impl Screen for StateScreen {
+    fn builder(builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+    }
+}
+
+#[test]
+fn mirrored_states() {
+    let mut app = App::new();
+    app.add_plugins((
+        TestRunnerPlugin::default(),
+        ScreenPlugin,
+        ScreenStatesPlugin,
+    ));
+    app.register_screen::<StateScreen>();
+    app.insert_resource(InitialScreen::new::<StateScreen>());
+    app.init_resource::<EnteredReady>();
+    app.add_systems(
+        OnEnter(ScreenStateOf::<StateScreen>::ready()),
+        |mut entered: ResMut<EnteredReady>, data: ScreenInfoRef<StateScreen>| {
+            entered.0 = data.data().state() == ScreenState::Ready;
+        },
+    );
+    app.add_systems(
+        Update,
+        (|entered: Res<EnteredReady>, mut commands: Commands| {
+            if entered.0 {
+                commands.write_message(AppExit::Success);
+            } else {
+                error!("OnEnter did not run before in_state.");
+                commands.write_message(AppExit::error());
+            }
+        })
+        .run_if(in_state(ScreenStateOf::<StateScreen>::ready())),
+    );
+    assert!(app.run().is_success());
+}
+
+#[test]
+fn mirrored_states_registered_before_plugin() {
+    let mut app = App::new();
+    app.add_plugins((TestRunnerPlugin::default(), ScreenPlugin));
+    app.register_screen::<StateScreen>();
+    app.add_plugins(ScreenStatesPlugin);
+    app.insert_resource(InitialScreen::new::<StateScreen>());
+    app.add_systems(
+        Update,
+        (|mut commands: Commands| {
+            commands.write_message(AppExit::Success);
+        })
+        .run_if(in_state(ScreenStateOf::<StateScreen>::ready())),
+    );
+    assert!(app.run().is_success());
+}
+
+#[test]
+fn mirrored_states_after_unregister() {
+    let mut app = App::new();
+    app.add_plugins((
+        TestRunnerPlugin::default(),
+        ScreenPlugin,
+        ScreenStatesPlugin,
+    ));
+    app.register_screen::<StateScreen>();
+    app.insert_resource(InitialScreen::new::<StateScreen>());
+    app.finish();
+    app.cleanup();
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(
+        app.world()
+            .resource::<State<ScreenStateOf<StateScreen>>>()
+            .state,
+        ScreenState::Ready
+    );
+    app.unregister_screen::<StateScreen>();
+    for _ in 0..10 {
+        app.update();
+    }
+    assert_eq!(
+        app.world()
+            .resource::<State<ScreenStateOf<StateScreen>>>()
+            .state,
+        ScreenState::Unloaded
+    );
+}
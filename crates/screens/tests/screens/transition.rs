@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use crate::prelude::*;
+
+/// Colour of the [TransitionOverlay] when the outgoing screen was cleaned up.
+#[derive(Resource, Debug, Default)]
+struct ColorAtCleanup(Option<Color>);
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct TransitionFromScreen<const OVERRIDE: bool>;
+impl<const OVERRIDE: bool> Screen for TransitionFromScreen<OVERRIDE> {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder.add_systems(ScreenSchedule::OnReady, |mut commands: Commands| {
+            let switch = switch_to_screen::<TransitionToScreen<OVERRIDE>>();
+            if OVERRIDE {
+                commands.trigger(
+                    switch.with_transition(FadeToColor::new(
+                        Color::WHITE,
+                        Duration::from_millis(100),
+                    )),
+                );
+            } else {
+                commands.trigger(switch);
+            }
+        });
+        builder
+    }
+}
+
+#[derive(Component, Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Reflect)]
+struct TransitionToScreen<const OVERRIDE: bool>;
+impl<const OVERRIDE: bool> Screen for TransitionToScreen<OVERRIDE> {
+    fn builder(mut builder: ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self> {
+        builder
+            .with_transition(FadeToColor::new(Color::BLACK, Duration::from_millis(100)))
+            .add_systems(ScreenSchedule::Update, Self::update);
+        builder
+    }
+}
+impl<const OVERRIDE: bool> TransitionToScreen<OVERRIDE> {
+    fn update(
+        mut commands: Commands,
+        transition: Res<ActiveTransition>,
+        at_cleanup: Res<ColorAtCleanup>,
+        overlays: Query<(), With<TransitionOverlay>>,
+    ) {
+        if transition.is_active() {
+            return;
+        }
+        let expected = if OVERRIDE { Color::WHITE } else { Color::BLACK };
+        if at_cleanup.0 == Some(expected) && overlays.is_empty() {
+            commands.write_message(AppExit::Success);
+        } else {
+            error!(?at_cleanup, "Transition did not cover the outgoing screen.");
+            commands.write_message(AppExit::error());
+        }
+    }
+}
+
+fn record_overlay(
+    overlays: Query<&BackgroundColor, With<TransitionOverlay>>,
+    mut at_cleanup: ResMut<ColorAtCleanup>,
+) {
+    at_cleanup.0 = overlays.iter().next().map(|color| color.0);
+}
+
+fn run_transition_test<const OVERRIDE: bool>() {
+    let mut app = get_test_app::<TransitionFromScreen<OVERRIDE>>();
+    app.register_screen::<TransitionToScreen<OVERRIDE>>();
+    app.init_resource::<ColorAtCleanup>();
+    app.add_systems(
+        on_screen_cleanup::<TransitionFromScreen<OVERRIDE>>(),
+        record_overlay,
+    );
+    assert!(app.run().is_success());
+}
+
+#[test]
+fn transition_finishes_before_cleanup() {
+    run_transition_test::<false>();
+}
+
+#[test]
+fn transition_override() {
+    run_transition_test::<true>();
+}
@@ -0,0 +1,221 @@
+//! Derive macro for `q_screens`. Use it through `q_screens`, which re-exports
+//! it with the `derive` feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    DeriveInput, Expr, Ident, LitBool, LitStr, Path, Type, meta::ParseNestedMeta, spanned::Spanned,
+};
+
+/// Schedule kinds which can be set with `#[screen(kind = systems)]`, and the
+/// matching `ScreenSchedule` variants.
+const SCHEDULES: &[(&str, &str)] = &[
+    ("update", "Update"),
+    ("fixed_update", "FixedUpdate"),
+    ("loading", "Loading"),
+    ("unloading", "Unloading"),
+    ("paused", "Paused"),
+    ("on_load", "OnLoad"),
+    ("on_ready", "OnReady"),
+    ("on_unload", "OnUnload"),
+    ("on_unloaded", "OnUnloaded"),
+    ("on_failed", "OnFailed"),
+];
+
+/// Derives `Screen`, wiring up the builder from `#[screen(...)]` attributes.
+/// The type still needs `Component`, `Reflect` and `Default`.
+///
+/// ```rust,ignore
+/// #[derive(Component, Reflect, Default, Screen)]
+/// #[screen(name = "World", load_strategy = "nonblocking", skip_unload)]
+/// #[screen(loading = init, update = (move_player, move_camera))]
+/// pub struct WorldScreen;
+/// ```
+///
+/// Options:
+/// - `name = "..."`: overrides `Screen::name`.
+/// - `params = Type`: sets `Screen::Params`.
+/// - `load_strategy = "blocking" | "nonblocking"`
+/// - `covered_policy = "run" | "pause" | "render_only"`
+/// - `skip_load`, `skip_unload`, `skip_history`, optionally `= true | false`.
+/// - `builder = path`: a `fn(ScreenScopeBuilder<Self>) -> ScreenScopeBuilder<Self>`
+///   called last, for anything the attributes can't express.
+///
+/// Systems are added with the schedule kind as the key, e.g.
+/// `on_ready = spawn_ui` or `update = (a, b).chain()`. The kinds are
+/// `update`, `fixed_update`, `loading`, `unloading`, `paused`, `on_load`,
+/// `on_ready`, `on_unload`, `on_unloaded` and `on_failed`. Anything else is a
+/// compile error.
+#[proc_macro_derive(Screen, attributes(screen))]
+pub fn derive_screen(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+#[derive(Default)]
+struct ScreenAttrs {
+    name: Option<LitStr>,
+    params: Option<Type>,
+    builder: Option<Path>,
+    load_strategy: Option<Ident>,
+    covered_policy: Option<Ident>,
+    skip_load: Option<LitBool>,
+    skip_unload: Option<LitBool>,
+    skip_history: Option<LitBool>,
+    /// Schedule variant and systems.
+    systems: Vec<(Ident, Expr)>,
+}
+
+impl ScreenAttrs {
+    fn parse(&mut self, meta: ParseNestedMeta) -> syn::Result<()> {
+        let Some(key) = meta.path.get_ident().map(Ident::to_string) else {
+            return Err(meta.error("expected a screen option or schedule kind"));
+        };
+        match key.as_str() {
+            "name" => set_once(&meta, &mut self.name, meta.value()?.parse()?),
+            "params" => set_once(&meta, &mut self.params, meta.value()?.parse()?),
+            "builder" => set_once(&meta, &mut self.builder, meta.value()?.parse()?),
+            "skip_load" | "skip_unload" | "skip_history" => {
+                let val = if meta.input.peek(syn::Token![=]) {
+                    meta.value()?.parse::<LitBool>()?
+                } else {
+                    LitBool::new(true, meta.path.span())
+                };
+                let slot = match key.as_str() {
+                    "skip_load" => &mut self.skip_load,
+                    "skip_unload" => &mut self.skip_unload,
+                    _ => &mut self.skip_history,
+                };
+                set_once(&meta, slot, val)
+            }
+            "load_strategy" => {
+                let lit: LitStr = meta.value()?.parse()?;
+                let variant = match lit.value().as_str() {
+                    "blocking" => "Blocking",
+                    "nonblocking" => "Nonblocking",
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "expected \"blocking\" or \"nonblocking\"",
+                        ));
+                    }
+                };
+                let variant = Ident::new(variant, lit.span());
+                set_once(&meta, &mut self.load_strategy, variant)
+            }
+            "covered_policy" => {
+                let lit: LitStr = meta.value()?.parse()?;
+                let variant = match lit.value().as_str() {
+                    "run" => "Run",
+                    "pause" => "Pause",
+                    "render_only" => "RenderOnly",
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            lit,
+                            "expected \"run\", \"pause\" or \"render_only\"",
+                        ));
+                    }
+                };
+                let variant = Ident::new(variant, lit.span());
+                set_once(&meta, &mut self.covered_policy, variant)
+            }
+            _ => {
+                let Some((_, variant)) = SCHEDULES.iter().find(|(kind, _)| *kind == key) else {
+                    let kinds = SCHEDULES
+                        .iter()
+                        .map(|(kind, _)| *kind)
+                        .collect::<Vec<_>>()
+                        .join(", ");
+                    return Err(meta.error(format!(
+                        "unknown screen schedule kind `{key}`, expected one of: {kinds}"
+                    )));
+                };
+                let systems = meta.value()?.parse()?;
+                self.systems
+                    .push((Ident::new(variant, meta.path.span()), systems));
+                Ok(())
+            }
+        }
+    }
+
+    /// Calls to the builder's `with_*` methods.
+    fn options(&self) -> Vec<TokenStream2> {
+        let mut options = vec![];
+        if let Some(variant) = &self.load_strategy {
+            options.push(quote! {
+                builder.with_load_strategy(::q_screens::prelude::LoadStrategy::#variant);
+            });
+        }
+        if let Some(variant) = &self.covered_policy {
+            options.push(quote! {
+                builder.with_covered_policy(::q_screens::prelude::CoveredPolicy::#variant);
+            });
+        }
+        let skips = [
+            ("with_skip_load", &self.skip_load),
+            ("with_skip_unload", &self.skip_unload),
+            ("with_skip_history", &self.skip_history),
+        ];
+        for (method, val) in skips {
+            if let Some(val) = val {
+                let method = Ident::new(method, val.span());
+                options.push(quote! { builder.#method(#val); });
+            }
+        }
+        options
+    }
+}
+
+fn set_once<T>(meta: &ParseNestedMeta, slot: &mut Option<T>, val: T) -> syn::Result<()> {
+    if slot.is_some() {
+        return Err(meta.error("duplicate screen option"));
+    }
+    *slot = Some(val);
+    Ok(())
+}
+
+fn expand(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let mut attrs = ScreenAttrs::default();
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("screen")) {
+        attr.parse_nested_meta(|meta| attrs.parse(meta))?;
+    }
+
+    let options = attrs.options();
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let params = attrs.params.map(|ty| quote! { type Params = #ty; });
+    let name = attrs.name.map(|name| {
+        quote! {
+            fn name() -> ::std::string::String {
+                ::std::string::String::from(#name)
+            }
+        }
+    });
+    let systems = attrs.systems.iter().map(|(variant, systems)| {
+        quote! {
+            builder.add_systems(::q_screens::prelude::ScreenSchedule::#variant, #systems);
+        }
+    });
+    let finish = match attrs.builder {
+        Some(path) => quote! { #path(builder) },
+        None => quote! { builder },
+    };
+
+    Ok(quote! {
+        impl #impl_generics ::q_screens::prelude::Screen for #ident #ty_generics #where_clause {
+            #params
+            #name
+            #[allow(unused_mut)]
+            fn builder(
+                mut builder: ::q_screens::prelude::ScreenScopeBuilder<Self>,
+            ) -> ::q_screens::prelude::ScreenScopeBuilder<Self> {
+                #(#options)*
+                #(#systems)*
+                #finish
+            }
+        }
+    })
+}
@@ -0,0 +1,7 @@
+use q_screens_derive::Screen;
+
+#[derive(Screen)]
+#[screen(covered_policy = "hide")]
+struct MenuScreen;
+
+fn main() {}
@@ -0,0 +1,7 @@
+use q_screens_derive::Screen;
+
+#[derive(Screen)]
+#[screen(load_strategy = "lazy")]
+struct MenuScreen;
+
+fn main() {}
@@ -0,0 +1,8 @@
+use q_screens_derive::Screen;
+
+#[derive(Screen)]
+#[screen(covered_policy = "run")]
+#[screen(covered_policy = "pause")]
+struct MenuScreen;
+
+fn main() {}
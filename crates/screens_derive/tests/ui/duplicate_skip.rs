@@ -0,0 +1,7 @@
+use q_screens_derive::Screen;
+
+#[derive(Screen)]
+#[screen(skip_unload, skip_unload = false)]
+struct MenuScreen;
+
+fn main() {}
@@ -0,0 +1,9 @@
+use q_screens_derive::Screen;
+
+fn spawn_ui() {}
+
+#[derive(Screen)]
+#[screen(on_enter = spawn_ui)]
+struct MenuScreen;
+
+fn main() {}